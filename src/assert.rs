@@ -0,0 +1,121 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Write};
+
+const CONTEXT: usize = 3;
+
+#[track_caller]
+pub fn assert_list_eq<'a, 'b, L, R, T, U>(left: &'a L, right: &'b R)
+where
+    L: ?Sized,
+    R: ?Sized,
+    &'a L: IntoIterator<Item = &'a T>,
+    &'b R: IntoIterator<Item = &'b U>,
+    T: PartialEq<U> + Debug + 'a,
+    U: Debug + 'b,
+{
+    let left: Vec<&T> = left.into_iter().collect();
+    let right: Vec<&U> = right.into_iter().collect();
+    let index = match left.iter().zip(&right).position(|(a, b)| **a != **b) {
+        Some(index) => index,
+        None if left.len() == right.len() => return,
+        None => left.len().min(right.len()),
+    };
+    panic!(
+        "lists differ at index {} (left len {}, right len {})\n  left[{}]: {}\n right[{}]: {}\n  left: {}\n right: {}",
+        index,
+        left.len(),
+        right.len(),
+        index,
+        element(&left, index),
+        index,
+        element(&right, index),
+        context(&left, index),
+        context(&right, index),
+    );
+}
+
+#[track_caller]
+pub fn assert_list_empty<'a, L, T>(left: &'a L)
+where
+    L: ?Sized,
+    &'a L: IntoIterator<Item = &'a T>,
+    T: PartialEq + Debug + 'a,
+{
+    assert_list_eq::<L, [T; 0], T, T>(left, &[]);
+}
+
+fn element<X: Debug>(items: &[&X], index: usize) -> String {
+    let mut out = String::new();
+    match items.get(index) {
+        Some(item) => write!(out, "{:?}", item).unwrap(),
+        None => out.push_str("<end>"),
+    }
+    out
+}
+
+fn context<X: Debug>(items: &[&X], index: usize) -> String {
+    let start = index.saturating_sub(CONTEXT).min(items.len());
+    let end = (index + CONTEXT + 1).min(items.len());
+    let mut out = String::from("[");
+    if start > 0 {
+        out.push_str("..");
+    }
+    for (i, item) in items[start..end].iter().enumerate() {
+        if i > 0 || start > 0 {
+            out.push_str(", ");
+        }
+        write!(out, "{:?}", item).unwrap();
+    }
+    if end < items.len() {
+        out.push_str(", ..");
+    }
+    out.push(']');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+    use alloc::vec;
+
+    #[test]
+    fn test_assert_list_eq() {
+        assert_list_eq!(linked_list![1, 2, 3], [1, 2, 3]);
+        assert_list_eq!(linked_list![1, 2, 3], linked_list![1, 2, 3]);
+        assert_list_eq!(linked_list![1, 2, 3], vec![1, 2, 3]);
+        assert_list_eq!(linked_list![1, 2, 3].view(1..), [2, 3]);
+        assert_list_eq!(LinkedList::<i32>::new(), []);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3")]
+    fn test_mismatch() {
+        assert_list_eq!(linked_list![1, 2, 3, 4, 5], [1, 2, 3, 5, 5]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "lists differ at index 2 (left len 2, right len 3)\n  left[2]: <end>\n right[2]: 3"
+    )]
+    fn test_shorter() {
+        assert_list_eq!(linked_list![1, 2], [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 0 (left len 1, right len 0)")]
+    fn test_not_empty() {
+        assert_list_eq!(linked_list![1], []);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "  left: [.., 7, 8, 9, 10, 11, 12, 13, ..]\n right: [.., 7, 8, 9, 0, 11, 12, 13, ..]"
+    )]
+    fn test_context() {
+        let left = LinkedList::from((0..200).collect::<vec::Vec<_>>());
+        let mut right = left.clone();
+        right[10] = 0;
+        assert_list_eq!(left, right);
+    }
+}
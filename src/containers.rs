@@ -0,0 +1,118 @@
+//! Conversions to and from the `heapless`, `arrayvec` and `smallvec` containers, each behind
+//! the feature of the same name.
+//!
+//! Every conversion moves the elements across in one pass and keeps their order. The
+//! fixed-capacity targets check the length up front, so an oversized list is handed back
+//! untouched.
+
+use crate::LinkedList;
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> From<heapless::Vec<T, N>> for LinkedList<T> {
+    fn from(value: heapless::Vec<T, N>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> From<arrayvec::ArrayVec<T, CAP>> for LinkedList<T> {
+    fn from(value: arrayvec::ArrayVec<T, CAP>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> From<smallvec::SmallVec<A>> for LinkedList<A::Item> {
+    fn from(value: smallvec::SmallVec<A>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> From<LinkedList<A::Item>> for smallvec::SmallVec<A> {
+    fn from(value: LinkedList<A::Item>) -> Self {
+        let mut vec = smallvec::SmallVec::with_capacity(value.len);
+        vec.extend(value);
+        vec
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Moves the elements into a `heapless::Vec`, or returns the list as it was if it holds
+    /// more than `N` elements.
+    #[cfg(feature = "heapless")]
+    pub fn try_into_heapless<const N: usize>(self) -> Result<heapless::Vec<T, N>, Self> {
+        if self.len > N {
+            return Err(self);
+        }
+        Ok(self.into_iter().collect())
+    }
+
+    /// Moves the elements into an `ArrayVec`, or returns the list as it was if it holds more
+    /// than `CAP` elements.
+    #[cfg(feature = "arrayvec")]
+    pub fn try_into_arrayvec<const CAP: usize>(self) -> Result<arrayvec::ArrayVec<T, CAP>, Self> {
+        if self.len > CAP {
+            return Err(self);
+        }
+        Ok(self.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_heapless() {
+        let vec: heapless::Vec<u8, 4> = heapless::Vec::from_slice(&[3, 1, 2]).unwrap();
+        let list = LinkedList::from(vec.clone());
+        assert_list_eq!(list, [3, 1, 2]);
+        assert_eq!(list.clone().try_into_heapless::<3>().unwrap(), vec);
+        assert_eq!(list.clone().try_into_heapless::<8>().unwrap(), [3, 1, 2]);
+
+        let list = list.try_into_heapless::<2>().unwrap_err();
+        assert_list_eq!(list, [3, 1, 2]);
+        assert!(LinkedList::<u8>::new()
+            .try_into_heapless::<0>()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn test_arrayvec() {
+        let vec = arrayvec::ArrayVec::from([3u8, 1, 2]);
+        let list = LinkedList::from(vec.clone());
+        assert_list_eq!(list, [3, 1, 2]);
+        assert_eq!(list.clone().try_into_arrayvec::<3>().unwrap(), vec);
+        assert_eq!(
+            list.clone().try_into_arrayvec::<8>().unwrap()[..],
+            [3, 1, 2]
+        );
+
+        let list = list.try_into_arrayvec::<2>().unwrap_err();
+        assert_list_eq!(list, [3, 1, 2]);
+        assert!(LinkedList::<u8>::new()
+            .try_into_arrayvec::<0>()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn test_smallvec() {
+        let inline: smallvec::SmallVec<[u8; 4]> = smallvec::smallvec![3, 1, 2];
+        let list = LinkedList::from(inline.clone());
+        assert_list_eq!(list, [3, 1, 2]);
+        let back: smallvec::SmallVec<[u8; 4]> = list.clone().into();
+        assert_eq!(back, inline);
+        assert!(!back.spilled());
+
+        let spilled: smallvec::SmallVec<[u8; 2]> = list.into();
+        assert!(spilled.spilled());
+        assert_eq!(spilled[..], [3, 1, 2]);
+        assert_list_eq!(LinkedList::from(spilled), [3, 1, 2]);
+    }
+}
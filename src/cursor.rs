@@ -0,0 +1,262 @@
+use alloc::boxed::Box;
+
+use crate::{LinkedList, Node};
+
+/// A position in a [`LinkedList`] that moves forward one node at a time and edits the list
+/// around itself in O(1).
+///
+/// Past the last element the cursor has no current element; it stays there on `move_next`.
+pub struct Cursor<'a, T> {
+    link: Option<&'a mut Option<Box<Node<T>>>>,
+    len: &'a mut usize,
+    index: usize,
+}
+
+impl<T> LinkedList<T> {
+    /// Returns a cursor at the first element.
+    pub fn cursor(&mut self) -> Cursor<'_, T> {
+        Cursor {
+            link: Some(&mut self.head),
+            len: &mut self.len,
+            index: 0,
+        }
+    }
+}
+
+impl<T> Cursor<'_, T> {
+    fn node(&self) -> Option<&Node<T>> {
+        self.link.as_deref()?.as_deref()
+    }
+
+    /// The index of the current element, or the length of the list when past the end.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn move_next(&mut self) {
+        let link = self.link.take().unwrap();
+        self.link = Some(match link {
+            Some(node) => {
+                self.index += 1;
+                &mut node.next
+            }
+            None => link,
+        });
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        self.node().map(|node| &node.data)
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        let node = self.link.as_deref_mut()?.as_deref_mut()?;
+        Some(&mut node.data)
+    }
+
+    pub fn peek_next(&self) -> Option<&T> {
+        self.node()?.next.as_deref().map(|node| &node.data)
+    }
+
+    /// Inserts `data` after the current element. Past the end, `data` is appended and becomes
+    /// the current element.
+    pub fn insert_after(&mut self, data: T) {
+        let link = self.link.as_deref_mut().unwrap();
+        let mut new = Node::new(data);
+        match link {
+            Some(node) => {
+                new.next = node.next.take();
+                node.next = Some(new);
+            }
+            None => *link = Some(new),
+        }
+        *self.len += 1;
+        self.debug_assert_len_consistent();
+    }
+
+    /// Moves all elements of `other` in after the current element, leaving `other` empty. Past
+    /// the end they are appended and the first of them becomes the current element.
+    pub fn splice_after(&mut self, other: &mut LinkedList<T>) {
+        let Some(head) = other.head.take() else {
+            return;
+        };
+        *self.len += core::mem::take(&mut other.len);
+        let link = self.link.as_deref_mut().unwrap();
+        let mut ptr = match link {
+            Some(node) => &mut node.next,
+            None => link,
+        };
+        let rest = ptr.replace(head);
+        while let Some(node) = ptr {
+            ptr = &mut node.next;
+        }
+        *ptr = rest;
+        self.debug_assert_len_consistent();
+    }
+
+    /// Removes the current element; the element after it becomes the current one.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let link = self.link.as_deref_mut().unwrap();
+        let mut node = link.take()?;
+        *link = node.next.take();
+        *self.len -= 1;
+        self.debug_assert_len_consistent();
+        Some(node.data)
+    }
+
+    /// Counts the nodes from the cursor to the end and, with the `index` nodes before it,
+    /// checks them against the stored length in debug builds.
+    fn debug_assert_len_consistent(&self) {
+        #[cfg(debug_assertions)]
+        crate::assert_len_consistent(self.link.as_deref().unwrap(), self.index, *self.len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_traverse() {
+        let mut list = linked_list![1, 2, 3];
+        let mut cursor = list.cursor();
+        let mut seen = Vec::new();
+        while let Some(&x) = cursor.current() {
+            assert_eq!(cursor.index(), seen.len());
+            assert_eq!(cursor.peek_next().copied(), [2, 3].get(seen.len()).copied());
+            seen.push(x);
+            cursor.move_next();
+        }
+        assert_eq!(seen, [1, 2, 3]);
+        cursor.move_next();
+        assert_eq!(cursor.index(), 3);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), None);
+
+        let mut empty = LinkedList::<i32>::new();
+        let mut cursor = empty.cursor();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), 0);
+    }
+
+    #[test]
+    fn test_insert_after() {
+        for at in 0..=3 {
+            let mut list = linked_list![1, 2, 3];
+            let mut cursor = list.cursor();
+            for _ in 0..at {
+                cursor.move_next();
+            }
+            cursor.insert_after(0);
+            let mut expected = alloc::vec![1, 2, 3];
+            expected.insert((at + 1).min(3), 0);
+            assert_list_eq!(list, expected);
+            assert_eq!(list.len(), 4);
+            list.debug_assert_len_consistent();
+        }
+
+        let mut list = LinkedList::new();
+        let mut cursor = list.cursor();
+        cursor.insert_after(1);
+        assert_eq!(cursor.current(), Some(&1));
+        cursor.insert_after(2);
+        assert_eq!(cursor.peek_next(), Some(&2));
+        assert_list_eq!(list, [1, 2]);
+    }
+
+    #[test]
+    fn test_remove_current() {
+        for at in 0..3 {
+            let mut list = linked_list![1, 2, 3];
+            let mut cursor = list.cursor();
+            for _ in 0..at {
+                cursor.move_next();
+            }
+            assert_eq!(cursor.remove_current(), Some(at as i32 + 1));
+            assert_eq!(cursor.current().copied(), [2, 3].get(at).copied());
+            let mut expected = alloc::vec![1, 2, 3];
+            expected.remove(at);
+            assert_list_eq!(list, expected);
+            assert_eq!(list.len(), 2);
+            list.debug_assert_len_consistent();
+        }
+
+        let mut list = linked_list![1, 2, 3];
+        let mut cursor = list.cursor();
+        while cursor.remove_current().is_some() {}
+        assert!(list.is_empty());
+        assert_eq!(list.cursor().remove_current(), None);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "stored length 5 differs from the 4 nodes in the list")]
+    fn test_len_inconsistent() {
+        let mut list = linked_list![1, 2, 3];
+        list.len = 4;
+        let mut cursor = list.cursor();
+        cursor.move_next();
+        cursor.insert_after(0);
+    }
+
+    #[test]
+    fn test_current_mut() {
+        let mut list = linked_list![1, 2, 3, 4];
+        let mut cursor = list.cursor();
+        while let Some(x) = cursor.current_mut() {
+            *x *= 2;
+            cursor.move_next();
+        }
+        assert_eq!(cursor.current_mut(), None);
+        assert_list_eq!(list, [2, 4, 6, 8]);
+
+        let mut list: LinkedList<i32> = (1..=10).collect();
+        let mut cursor = list.cursor();
+        while let Some(x) = cursor.current_mut() {
+            if *x % 3 == 0 {
+                cursor.remove_current();
+            } else {
+                *x += 100;
+                cursor.move_next();
+            }
+        }
+        assert_list_eq!(list, [101, 102, 104, 105, 107, 108, 110]);
+        list.debug_assert_len_consistent();
+    }
+
+    #[test]
+    fn test_splice_after() {
+        for at in 0..=3 {
+            let mut list = linked_list![1, 2, 3];
+            let mut other = linked_list![7, 8];
+            let mut cursor = list.cursor();
+            for _ in 0..at {
+                cursor.move_next();
+            }
+            cursor.splice_after(&mut other);
+            assert_eq!(cursor.index(), at);
+            let mut expected = alloc::vec![1, 2, 3];
+            let split = (at + 1).min(3);
+            expected.splice(split..split, [7, 8]);
+            assert_list_eq!(list, expected);
+            assert_eq!(list.len(), 5);
+            list.debug_assert_len_consistent();
+            assert!(other.is_empty());
+            other.debug_assert_len_consistent();
+        }
+
+        let mut list = linked_list![1, 2];
+        let mut cursor = list.cursor();
+        cursor.splice_after(&mut LinkedList::new());
+        assert_eq!(cursor.peek_next(), Some(&2));
+        assert_list_eq!(list, [1, 2]);
+
+        let mut list = LinkedList::new();
+        let mut cursor = list.cursor();
+        cursor.splice_after(&mut linked_list![4, 5, 6]);
+        assert_eq!(cursor.current(), Some(&4));
+        assert_list_eq!(list, [4, 5, 6]);
+        assert_eq!(list.len(), 3);
+    }
+}
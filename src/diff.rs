@@ -0,0 +1,263 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{LinkedList, Node};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<T> {
+    Keep(T),
+    Insert(T),
+    Delete(T),
+}
+
+impl<T> LinkedList<T> {
+    /// Computes an edit script turning `self` into `other`.
+    ///
+    /// This uses Myers' bisection algorithm, which takes O((n + m) d) time and linear space,
+    /// where `d` is the number of inserted and deleted elements.
+    pub fn diff(&self, other: &LinkedList<T>) -> Vec<Edit<T>>
+    where
+        T: PartialEq + Clone,
+    {
+        let a: Vec<&T> = self.iter().collect();
+        let b: Vec<&T> = other.iter().collect();
+        let mut edits = Vec::with_capacity(a.len().max(b.len()));
+        diff_slices(&a, &b, &mut edits);
+        edits
+    }
+
+    /// Replays an edit script produced by [`diff`](LinkedList::diff) onto the list.
+    ///
+    /// Kept elements are relinked rather than replaced by the values carried in the script.
+    /// Panics if the number of kept and deleted elements does not match the length of the list.
+    pub fn apply_patch<I: IntoIterator<Item = Edit<T>>>(&mut self, edits: I) {
+        let edits: Vec<Edit<T>> = edits.into_iter().collect();
+        let consumed = edits
+            .iter()
+            .filter(|edit| !matches!(edit, Edit::Insert(_)))
+            .count();
+        assert!(consumed == self.len, "edit script does not match the list");
+        let mut old = LinkedList {
+            head: self.head.take(),
+            len: self.len,
+        };
+        self.len = 0;
+        let mut len = 0;
+        let mut tail = &mut self.head;
+        for edit in edits {
+            match edit {
+                Edit::Keep(_) => {
+                    let node = old.pop_front_node().unwrap();
+                    tail = &mut tail.insert(node).next;
+                    len += 1;
+                }
+                Edit::Insert(data) => {
+                    tail = &mut tail.insert(Node::new(data)).next;
+                    len += 1;
+                }
+                Edit::Delete(_) => {
+                    old.pop_front();
+                }
+            }
+        }
+        self.len = len;
+        self.debug_assert_len_consistent();
+    }
+}
+
+fn diff_slices<T: PartialEq + Clone>(a: &[&T], b: &[&T], edits: &mut Vec<Edit<T>>) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    edits.extend(a[..prefix].iter().map(|&x| Edit::Keep(x.clone())));
+
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+    if a_mid.is_empty() {
+        edits.extend(b_mid.iter().map(|&x| Edit::Insert(x.clone())));
+    } else if b_mid.is_empty() {
+        edits.extend(a_mid.iter().map(|&x| Edit::Delete(x.clone())));
+    } else if let Some((x, y)) = bisect(a_mid, b_mid) {
+        diff_slices(&a_mid[..x], &b_mid[..y], edits);
+        diff_slices(&a_mid[x..], &b_mid[y..], edits);
+    } else {
+        edits.extend(a_mid.iter().map(|&x| Edit::Delete(x.clone())));
+        edits.extend(b_mid.iter().map(|&x| Edit::Insert(x.clone())));
+    }
+
+    edits.extend(a[a.len() - suffix..].iter().map(|&x| Edit::Keep(x.clone())));
+}
+
+/// Finds the middle snake of the edit graph and returns the point to split both inputs at.
+fn bisect<T: PartialEq>(a: &[&T], b: &[&T]) -> Option<(usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max_d = (n + m + 1) / 2;
+    let offset = max_d;
+    let size = 2 * max_d + 2;
+    let mut forward = vec![-1; size as usize];
+    let mut backward = vec![-1; size as usize];
+    forward[(offset + 1) as usize] = 0;
+    backward[(offset + 1) as usize] = 0;
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+    let (mut k1_start, mut k1_end, mut k2_start, mut k2_end) = (0, 0, 0, 0);
+
+    for d in 0..max_d {
+        let mut k1 = -d + k1_start;
+        while k1 <= d - k1_end {
+            let k1_offset = (offset + k1) as usize;
+            let mut x1 = if k1 == -d || (k1 != d && forward[k1_offset - 1] < forward[k1_offset + 1])
+            {
+                forward[k1_offset + 1]
+            } else {
+                forward[k1_offset - 1] + 1
+            };
+            let mut y1 = x1 - k1;
+            while x1 < n && y1 < m && a[x1 as usize] == b[y1 as usize] {
+                x1 += 1;
+                y1 += 1;
+            }
+            forward[k1_offset] = x1;
+            if x1 > n {
+                k1_end += 2;
+            } else if y1 > m {
+                k1_start += 2;
+            } else if odd {
+                let k2_offset = offset + delta - k1;
+                if (0..size).contains(&k2_offset) && backward[k2_offset as usize] != -1 {
+                    let x2 = n - backward[k2_offset as usize];
+                    if x1 >= x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+            k1 += 2;
+        }
+
+        let mut k2 = -d + k2_start;
+        while k2 <= d - k2_end {
+            let k2_offset = (offset + k2) as usize;
+            let mut x2 =
+                if k2 == -d || (k2 != d && backward[k2_offset - 1] < backward[k2_offset + 1]) {
+                    backward[k2_offset + 1]
+                } else {
+                    backward[k2_offset - 1] + 1
+                };
+            let mut y2 = x2 - k2;
+            while x2 < n && y2 < m && a[(n - x2 - 1) as usize] == b[(m - y2 - 1) as usize] {
+                x2 += 1;
+                y2 += 1;
+            }
+            backward[k2_offset] = x2;
+            if x2 > n {
+                k2_end += 2;
+            } else if y2 > m {
+                k2_start += 2;
+            } else if !odd {
+                let k1_offset = offset + delta - k2;
+                if (0..size).contains(&k1_offset) && forward[k1_offset as usize] != -1 {
+                    let x1 = forward[k1_offset as usize];
+                    let y1 = offset + x1 - k1_offset;
+                    if x1 >= n - x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+            k2 += 2;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Edit::{self, Delete, Insert, Keep};
+    use crate::testing::Rng;
+    use crate::LinkedList;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn changes<T>(edits: &[Edit<T>]) -> usize {
+        edits.iter().filter(|edit| !matches!(edit, Keep(_))).count()
+    }
+
+    fn lcs<T: PartialEq>(a: &LinkedList<T>, b: &LinkedList<T>) -> usize {
+        let mut row = vec![0; b.len() + 1];
+        for x in a.iter() {
+            let mut diagonal = 0;
+            for (j, y) in b.iter().enumerate() {
+                let above = row[j + 1];
+                row[j + 1] = if x == y {
+                    diagonal + 1
+                } else {
+                    above.max(row[j])
+                };
+                diagonal = above;
+            }
+        }
+        row[b.len()]
+    }
+
+    #[test]
+    fn test_diff() {
+        let a = linked_list![1, 2, 3];
+        let b = linked_list![1, 3, 4];
+        assert_eq!(a.diff(&b), vec![Keep(1), Delete(2), Keep(3), Insert(4)]);
+        assert_eq!(a.diff(&a), vec![Keep(1), Keep(2), Keep(3)]);
+        assert_eq!(
+            LinkedList::new().diff(&a),
+            vec![Insert(1), Insert(2), Insert(3)]
+        );
+        assert_eq!(
+            a.diff(&LinkedList::new()),
+            vec![Delete(1), Delete(2), Delete(3)]
+        );
+
+        let a = LinkedList::from(vec!["a", "b", "c", "a", "b", "b", "a"]);
+        let b = LinkedList::from(vec!["c", "b", "a", "b", "a", "c"]);
+        assert_eq!(changes(&a.diff(&b)), 5);
+    }
+
+    #[test]
+    fn test_apply_patch() {
+        let mut a: LinkedList<String> = ["fn main() {", "    foo();", "}"]
+            .map(ToString::to_string)
+            .into();
+        let b: LinkedList<String> = ["fn main() {", "    bar();", "    baz();", "}"]
+            .map(ToString::to_string)
+            .into();
+        a.apply_patch(a.diff(&b));
+        assert_eq!(a.len(), 4);
+        assert!(a == b);
+
+        let mut rng = Rng(0x853c_49e6_748f_ea9b);
+        for _ in 0..200 {
+            let a_len = (rng.next() % 30) as usize;
+            let b_len = (rng.next() % 30) as usize;
+            let mut a = LinkedList::from(rng.vec(a_len, 4));
+            let b = LinkedList::from(rng.vec(b_len, 4));
+            let edits = a.diff(&b);
+            assert_eq!(changes(&edits), a_len + b_len - 2 * lcs(&a, &b));
+            a.apply_patch(edits);
+            assert!(a == b);
+        }
+
+        let a: LinkedList<u64> = (0..3000).map(|x| x % 97).collect::<Vec<_>>().into();
+        let b: LinkedList<u64> = (0..3000).map(|x| x % 89).collect::<Vec<_>>().into();
+        let mut c = a.clone();
+        c.apply_patch(a.diff(&b));
+        assert!(c == b);
+    }
+
+    #[test]
+    #[should_panic(expected = "edit script does not match the list")]
+    fn test_apply_patch_mismatch() {
+        let mut list = linked_list![1, 2];
+        list.apply_patch([Keep(1)]);
+    }
+}
@@ -0,0 +1,136 @@
+//! Lists of unsized elements: `LinkedList<str>` and `LinkedList<[T]>`.
+//!
+//! The element is stored inline at the end of its node, so a `LinkedList<str>` costs one
+//! allocation per element where a `LinkedList<Box<str>>` costs two. Such nodes are allocated
+//! by hand, as there is no stable way to build a `Box` of a custom unsized type. Elements
+//! cannot be moved out by value, so they come back out as `Box<str>` and `Box<[T]>`.
+
+use alloc::alloc::{alloc, handle_alloc_error, Layout};
+use alloc::boxed::Box;
+use core::ptr;
+
+use crate::{LinkedList, Node};
+
+impl<T: Copy> Node<[T]> {
+    fn from_slice(data: &[T]) -> Box<Self> {
+        let (layout, offset) = Layout::new::<Option<Box<Node<[T]>>>>()
+            .extend(Layout::array::<T>(data.len()).expect("slice too large for a node"))
+            .expect("slice too large for a node");
+        let layout = layout.pad_to_align();
+        // SAFETY: `layout` is never zero-sized, as it contains the `next` link.
+        let raw = unsafe { alloc(layout) };
+        if raw.is_null() {
+            handle_alloc_error(layout);
+        }
+        let node = ptr::slice_from_raw_parts_mut(raw.cast::<T>(), data.len()) as *mut Node<[T]>;
+        // SAFETY: `Node` is `repr(C)`, so `layout` and `offset` are exactly its layout for
+        // `data.len()` elements and the offset of `data`, which is what `node`'s metadata says.
+        // Both fields are initialized before the `Box` takes ownership of the allocation, and
+        // `T: Copy` means copying the elements cannot panic halfway.
+        unsafe {
+            ptr::addr_of_mut!((*node).next).write(None);
+            debug_assert_eq!(
+                ptr::addr_of_mut!((*node).data).cast::<u8>(),
+                raw.add(offset)
+            );
+            ptr::addr_of_mut!((*node).data)
+                .cast::<T>()
+                .copy_from_nonoverlapping(data.as_ptr(), data.len());
+            Box::from_raw(node)
+        }
+    }
+}
+
+impl Node<str> {
+    fn from_str(data: &str) -> Box<Self> {
+        let node = Box::into_raw(Node::from_slice(data.as_bytes()));
+        // SAFETY: `Node<[u8]>` and `Node<str>` have the same layout, and the bytes are UTF-8.
+        unsafe { Box::from_raw(node as *mut Node<str>) }
+    }
+}
+
+impl<T: Copy> LinkedList<[T]> {
+    pub fn append_slice(&mut self, data: &[T]) -> &mut Self {
+        self.push_back_node(Node::from_slice(data));
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    /// Removes the first element, copied into a `Box` of its own.
+    pub fn pop_front_boxed(&mut self) -> Option<Box<[T]>> {
+        self.pop_front_node().map(|node| Box::from(&node.data))
+    }
+}
+
+impl LinkedList<str> {
+    pub fn append_str(&mut self, data: &str) -> &mut Self {
+        self.push_back_node(Node::from_str(data));
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    /// Removes the first element, copied into a `Box` of its own.
+    pub fn pop_front_boxed(&mut self) -> Option<Box<str>> {
+        self.pop_front_node().map(|node| Box::from(&node.data))
+    }
+}
+
+impl<'a> FromIterator<&'a str> for LinkedList<str> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        let mut tail = &mut list.head;
+        for data in iter {
+            tail = &mut tail.insert(Node::from_str(data)).next;
+            list.len += 1;
+        }
+        list.debug_assert_len_consistent();
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+    use alloc::boxed::Box;
+    use alloc::format;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_str_list() {
+        let mut list: LinkedList<str> = ["alpha", "beta"].into_iter().collect();
+        list.append_str("gamma").append_str("");
+        assert_eq!(list.len(), 4);
+        assert_eq!(&list[1], "beta");
+        assert_eq!(list.first(), Some("alpha"));
+        assert_eq!(list.last(), Some(""));
+        assert_eq!(list.iter().map(str::len).sum::<usize>(), 14);
+        assert_eq!(format!("{}", list), "alpha -> beta -> gamma ->  ");
+
+        list[2].make_ascii_uppercase();
+        assert_eq!(list.pop_front_boxed().as_deref(), Some("alpha"));
+        let rest: Vec<&str> = list.iter().collect();
+        assert_eq!(rest, ["beta", "GAMMA", ""]);
+        list.clear();
+        assert_eq!(list.pop_front_boxed(), None);
+        assert_eq!(format!("{}", list), "None");
+    }
+
+    #[test]
+    fn test_slice_list() {
+        let mut list = LinkedList::<[u64]>::new();
+        list.append_slice(&[1, 2, 3])
+            .append_slice(&[])
+            .append_slice(&[u64::MAX]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0], [1, 2, 3]);
+        assert!(list[1].is_empty());
+        list[2][0] = 4;
+        assert_eq!(list.last(), Some(&[4][..]));
+        assert_eq!(list.pop_front_boxed(), Some(Box::from([1, 2, 3])));
+        assert_eq!(list.iter().map(<[u64]>::len).collect::<Vec<_>>(), [0, 1]);
+
+        let mut list = LinkedList::<[()]>::new();
+        list.append_slice(&[(); 3]);
+        assert_eq!(list[0].len(), 3);
+    }
+}
@@ -0,0 +1,209 @@
+use alloc::vec::Vec;
+
+use crate::LinkedList;
+
+/// Identifies an element of a [`HandleList`]. A handle stays valid until its element is
+/// removed; after that every lookup with it returns `None`, even if the slot is reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeHandle {
+    index: usize,
+    generation: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Slot<T> {
+    generation: u64,
+    data: Option<T>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// A doubly linked list stored in a slab, so that elements can be removed in O(1) through
+/// the [`NodeHandle`] returned when they were appended.
+///
+/// [`LinkedList`] owns its nodes through `Box`es and has no back links, so unlinking an
+/// arbitrary node there always needs a walk from the head.
+#[derive(Debug, Clone)]
+pub struct HandleList<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+impl<T> Default for HandleList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> HandleList<T> {
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn append_with_handle(&mut self, data: T) -> NodeHandle {
+        let slot = Slot {
+            generation: 0,
+            data: Some(data),
+            prev: self.tail,
+            next: None,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                let generation = self.slots[index].generation;
+                self.slots[index] = Slot { generation, ..slot };
+                index
+            }
+            None => {
+                self.slots.push(slot);
+                self.slots.len() - 1
+            }
+        };
+        match self.tail {
+            Some(tail) => self.slots[tail].next = Some(index),
+            None => self.head = Some(index),
+        }
+        self.tail = Some(index);
+        self.len += 1;
+        NodeHandle {
+            index,
+            generation: self.slots[index].generation,
+        }
+    }
+
+    fn slot(&self, handle: NodeHandle) -> Option<&Slot<T>> {
+        self.slots
+            .get(handle.index)
+            .filter(|slot| slot.generation == handle.generation && slot.data.is_some())
+    }
+
+    pub fn get_handle(&self, handle: NodeHandle) -> Option<&T> {
+        self.slot(handle)?.data.as_ref()
+    }
+
+    pub fn get_handle_mut(&mut self, handle: NodeHandle) -> Option<&mut T> {
+        self.slot(handle)?;
+        self.slots[handle.index].data.as_mut()
+    }
+
+    /// Removes the element behind `handle`, or returns `None` if it was already removed.
+    pub fn remove_handle(&mut self, handle: NodeHandle) -> Option<T> {
+        let (prev, next) = {
+            let slot = self.slot(handle)?;
+            (slot.prev, slot.next)
+        };
+        match prev {
+            Some(prev) => self.slots[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.slots[next].prev = prev,
+            None => self.tail = prev,
+        }
+        let slot = &mut self.slots[handle.index];
+        slot.generation += 1;
+        slot.prev = None;
+        slot.next = None;
+        self.free.push(handle.index);
+        self.len -= 1;
+        slot.data.take()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        let mut index = self.head;
+        core::iter::from_fn(move || {
+            let slot = &self.slots[index?];
+            index = slot.next;
+            slot.data.as_ref()
+        })
+    }
+}
+
+impl<T> From<HandleList<T>> for LinkedList<T> {
+    fn from(mut list: HandleList<T>) -> Self {
+        let mut index = list.head;
+        core::iter::from_fn(|| {
+            let slot = &mut list.slots[index?];
+            index = slot.next;
+            slot.data.take()
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HandleList;
+    use crate::testing::Rng;
+    use crate::LinkedList;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_stale_handle() {
+        let mut list = HandleList::new();
+        let a = list.append_with_handle(1);
+        let b = list.append_with_handle(2);
+        let c = list.append_with_handle(3);
+        assert_eq!(list.remove_handle(b), Some(2));
+        assert_eq!(list.remove_handle(b), None);
+        assert_eq!(list.get_handle(b), None);
+
+        let d = list.append_with_handle(4);
+        assert_eq!(d.index, b.index);
+        assert_eq!(list.get_handle(b), None);
+        assert_eq!(list.remove_handle(b), None);
+        assert_eq!(list.get_handle(d), Some(&4));
+
+        *list.get_handle_mut(a).unwrap() = 10;
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 3, 4]);
+        assert_eq!(list.remove_handle(a), Some(10));
+        assert_eq!(list.remove_handle(d), Some(4));
+        assert_eq!(list.remove_handle(c), Some(3));
+        assert!(list.is_empty());
+        assert_eq!(list.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_churn() {
+        let mut rng = Rng(0x5eed);
+        let mut list = HandleList::new();
+        let keep: Vec<_> = (0..8).map(|i| (list.append_with_handle(-i), -i)).collect();
+        let mut live = Vec::new();
+        for i in 0..2000 {
+            if live.len() < 32 && !rng.next().is_multiple_of(3) {
+                live.push((list.append_with_handle(i), i));
+            } else if !live.is_empty() {
+                let at = rng.next() as usize % live.len();
+                let (handle, value) = live.swap_remove(at);
+                assert_eq!(list.remove_handle(handle), Some(value));
+                assert_eq!(list.get_handle(handle), None);
+            }
+            assert_eq!(list.len(), keep.len() + live.len());
+        }
+        assert!(list.slots.len() <= keep.len() + 32);
+        for &(handle, value) in keep.iter().chain(&live) {
+            assert_eq!(list.get_handle(handle), Some(&value));
+        }
+
+        let values: Vec<i32> = list.iter().copied().collect();
+        let linked = LinkedList::from(list);
+        assert_list_eq!(linked, values);
+        assert_eq!(values[..8], [0, -1, -2, -3, -4, -5, -6, -7]);
+    }
+}
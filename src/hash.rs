@@ -0,0 +1,75 @@
+use core::hash::{Hash, Hasher};
+
+use crate::LinkedList;
+
+/// A [`LinkedList`] of byte-like elements that hashes each element as one byte slice.
+///
+/// Equal lists must have equal bytes for the hash to stay consistent with `Eq`, which holds
+/// for `String`, `&str`, `Vec<u8>` and the like.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteHashableList<T>(pub LinkedList<T>);
+
+impl<T: AsRef<[u8]>> Hash for ByteHashableList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.0.len());
+        for data in &self.0 {
+            let bytes = data.as_ref();
+            state.write_usize(bytes.len());
+            state.write(bytes);
+        }
+    }
+}
+
+impl<T> From<LinkedList<T>> for ByteHashableList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        Self(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteHashableList;
+    use crate::LinkedList;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use core::hash::{Hash, Hasher};
+
+    extern crate std;
+
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn strings(items: &[&str]) -> ByteHashableList<String> {
+        items
+            .iter()
+            .map(ToString::to_string)
+            .collect::<LinkedList<_>>()
+            .into()
+    }
+
+    #[test]
+    fn test_hash_consistency() {
+        let a = strings(&["ab", "c", ""]);
+        let b = strings(&["ab", "c", ""]);
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_ne!(hash(&strings(&["ab", "c"])), hash(&strings(&["a", "bc"])));
+        assert_ne!(hash(&strings(&["a"])), hash(&strings(&["a", ""])));
+        assert_ne!(a, strings(&["ab", "c"]));
+
+        let bytes: ByteHashableList<Vec<u8>> = ByteHashableList(linked_list![alloc::vec![1, 2]]);
+        assert_eq!(hash(&bytes), hash(&bytes.clone()));
+        let strs = ByteHashableList(linked_list!["ab", "c", ""]);
+        assert_eq!(hash(&strs), hash(&a));
+        let empty = ByteHashableList(LinkedList::<&str>::new());
+        assert_eq!(
+            hash(&empty),
+            hash(&ByteHashableList(LinkedList::<String>::new()))
+        );
+    }
+}
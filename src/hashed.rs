@@ -0,0 +1,178 @@
+//! Operations backed by `hashbrown`'s `HashMap`, behind the `hashbrown` feature.
+
+use core::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::{Group, LinkedList};
+
+impl<T> LinkedList<T> {
+    /// Counts the occurrences of each distinct element in a single pass, cloning each one
+    /// the first time it is seen.
+    pub fn counts(&self) -> HashMap<T, usize>
+    where
+        T: Hash + Eq + Clone,
+    {
+        let mut counts = HashMap::new();
+        for data in self {
+            match counts.get_mut(data) {
+                Some(count) => *count += 1,
+                None => {
+                    counts.insert(data.clone(), 1);
+                }
+            }
+        }
+        counts
+    }
+
+    /// Counts the elements by the key `key` computes for each of them.
+    pub fn counts_by<K: Hash + Eq, F: FnMut(&T) -> K>(&self, mut key: F) -> HashMap<K, usize> {
+        let mut counts = HashMap::new();
+        for data in self {
+            *counts.entry(key(data)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Moves every element into the list for its key, keeping the original order within each
+    /// list. Equal keys need not be adjacent. The nodes are relinked, not reallocated.
+    pub fn group_to_map<K: Hash + Eq, F: FnMut(&T) -> K>(
+        self,
+        key: F,
+    ) -> HashMap<K, LinkedList<T>> {
+        let mut groups = HashMap::new();
+        self.group_into(&mut groups, key, |groups, key| {
+            groups.entry(key).or_insert_with(Group::new)
+        });
+        groups
+            .into_iter()
+            .map(|(key, group)| (key, group.list))
+            .collect()
+    }
+
+    /// Like [`is_subset`](Self::is_subset), counting duplicates the same way, in O(n + m).
+    /// Stops at the first element of `self` that `other` runs out of.
+    pub fn is_subset_hashed(&self, other: &LinkedList<T>) -> bool
+    where
+        T: Hash + Eq,
+    {
+        if self.len > other.len {
+            return false;
+        }
+        let mut available: HashMap<&T, usize> = HashMap::new();
+        for data in other {
+            *available.entry(data).or_insert(0) += 1;
+        }
+        self.iter().all(|data| match available.get_mut(data) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        })
+    }
+
+    /// Like [`is_disjoint`](Self::is_disjoint), in O(n + m). Stops at the first shared element.
+    pub fn is_disjoint_hashed(&self, other: &LinkedList<T>) -> bool
+    where
+        T: Hash + Eq,
+    {
+        if self.is_empty() || other.is_empty() {
+            return true;
+        }
+        let seen: HashSet<&T> = other.iter().collect();
+        !self.iter().any(|data| seen.contains(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+
+    #[test]
+    fn test_counts() {
+        let list = linked_list!["open", "read", "read", "close", "read", "open"];
+        let counts = list.counts();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts["read"], 3);
+        assert_eq!(counts["open"], 2);
+        assert_eq!(counts["close"], 1);
+        assert_eq!(counts.values().sum::<usize>(), list.len());
+
+        let unique: LinkedList<i32> = (0..10).collect();
+        let counts = unique.counts();
+        assert_eq!(counts.len(), 10);
+        assert!(counts.values().all(|&count| count == 1));
+        assert!(LinkedList::<i32>::new().counts().is_empty());
+
+        let lengths = list.counts_by(|event| event.len());
+        assert_eq!(lengths[&4], 5);
+        assert_eq!(lengths[&5], 1);
+        assert_eq!(lengths.values().sum::<usize>(), list.len());
+        assert!(LinkedList::<i32>::new().counts_by(|&x| x % 2).is_empty());
+    }
+
+    #[test]
+    fn test_group_to_map() {
+        let events = linked_list![("net", 1), ("disk", 2), ("net", 3), ("cpu", 4), ("net", 5)];
+        let groups = events.group_to_map(|event| event.0);
+        assert_eq!(groups.len(), 3);
+        assert_list_eq!(groups["net"], [("net", 1), ("net", 3), ("net", 5)]);
+        assert_list_eq!(groups["disk"], [("disk", 2)]);
+        assert_list_eq!(groups["cpu"], [("cpu", 4)]);
+        assert_eq!(groups.values().map(LinkedList::len).sum::<usize>(), 5);
+        for group in groups.values() {
+            group.debug_assert_len_consistent();
+        }
+
+        let list: LinkedList<i32> = (0..100).collect();
+        let groups = list.group_to_map(|_| ());
+        assert_list_eq!(groups[&()], (0..100).collect::<alloc::vec::Vec<_>>());
+
+        let list: LinkedList<i32> = (0..1000).map(|x| x * 7 % 1000).collect();
+        let groups = list.clone().group_to_map(|x| x % 100);
+        assert_eq!(
+            groups.values().map(LinkedList::len).sum::<usize>(),
+            list.len()
+        );
+        for (key, group) in &groups {
+            let expected: alloc::vec::Vec<i32> =
+                list.iter().copied().filter(|x| x % 100 == *key).collect();
+            assert_list_eq!(*group, expected);
+        }
+        assert!(LinkedList::<i32>::new().group_to_map(|&x| x).is_empty());
+    }
+
+    #[test]
+    fn test_is_subset_and_is_disjoint_hashed() {
+        let list = linked_list![3, 1, 2, 1];
+        let empty = LinkedList::<i32>::new();
+        let cases = [
+            linked_list![1, 2],
+            linked_list![1, 1, 3],
+            linked_list![1, 1, 1],
+            linked_list![4],
+            linked_list![5, 2],
+            linked_list![3, 1, 2, 1],
+            LinkedList::new(),
+        ];
+        for other in &cases {
+            assert_eq!(other.is_subset_hashed(&list), other.is_subset(&list));
+            assert_eq!(list.is_subset_hashed(other), list.is_subset(other));
+            assert_eq!(other.is_disjoint_hashed(&list), other.is_disjoint(&list));
+            assert_eq!(list.is_disjoint_hashed(other), list.is_disjoint(other));
+        }
+        assert!(linked_list![1, 1, 3].is_subset_hashed(&list));
+        assert!(!linked_list![1, 1, 1].is_subset_hashed(&list));
+        assert!(list.is_subset_hashed(&list));
+        assert!(empty.is_subset_hashed(&list));
+        assert!(empty.is_subset_hashed(&empty));
+        assert!(!list.is_subset_hashed(&empty));
+
+        assert!(list.is_disjoint_hashed(&linked_list![4, 5, 4]));
+        assert!(!list.is_disjoint_hashed(&list));
+        assert!(list.is_disjoint_hashed(&empty));
+        assert!(empty.is_disjoint_hashed(&list));
+        assert!(empty.is_disjoint_hashed(&empty));
+    }
+}
@@ -0,0 +1,218 @@
+use alloc::vec::Vec;
+
+use crate::LinkedList;
+
+const MIN_CHUNK: usize = 16;
+
+/// A list split into linked chunks of about `√n` elements, so that `get`, `insert` and
+/// `remove` by index take O(√n) instead of O(n).
+///
+/// Chunks are split when they grow past twice the target size and merged with a neighbour
+/// when they shrink, so the index stays balanced through every mutation. Plain
+/// [`LinkedList`]s pay nothing for it; convert with `From` in either direction.
+#[derive(Debug, Clone)]
+pub struct IndexedList<T> {
+    chunks: Vec<LinkedList<T>>,
+    len: usize,
+}
+
+impl<T> Default for IndexedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IndexedList<T> {
+    pub fn new() -> Self {
+        Self {
+            chunks: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn chunk_size(len: usize) -> usize {
+        len.isqrt().max(MIN_CHUNK)
+    }
+
+    /// Returns the chunk holding `index` and the position inside it.
+    fn locate(&self, mut index: usize) -> Option<(usize, usize)> {
+        for (i, chunk) in self.chunks.iter().enumerate() {
+            if index < chunk.len() {
+                return Some((i, index));
+            }
+            index -= chunk.len();
+        }
+        None
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (chunk, offset) = self.locate(index)?;
+        self.chunks[chunk].iter().nth(offset)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (chunk, offset) = self.locate(index)?;
+        self.chunks[chunk].iter_mut().nth(offset)
+    }
+
+    /// Inserts `data` at `index`, or appends it if `index` is past the end.
+    pub fn insert(&mut self, data: T, index: usize) -> &mut Self {
+        let (chunk, offset) = match self.locate(index) {
+            Some(position) => position,
+            None if self.chunks.is_empty() => {
+                self.chunks.push(LinkedList::new());
+                (0, 0)
+            }
+            None => (self.chunks.len() - 1, usize::MAX),
+        };
+        self.chunks[chunk].insert(data, offset);
+        self.len += 1;
+        let chunk_len = self.chunks[chunk].len();
+        if chunk_len > 2 * Self::chunk_size(self.len) {
+            let back = self.chunks[chunk].detach_from(chunk_len / 2);
+            self.chunks.insert(chunk + 1, back);
+        }
+        self
+    }
+
+    pub fn push_back(&mut self, data: T) -> &mut Self {
+        self.insert(data, self.len)
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let (chunk, offset) = self.locate(index)?;
+        let data = self.chunks[chunk].pop_at(offset);
+        self.len -= 1;
+        if self.chunks[chunk].is_empty() {
+            self.chunks.remove(chunk);
+        } else if chunk + 1 < self.chunks.len() {
+            self.merge_if_small(chunk);
+        } else if chunk > 0 {
+            self.merge_if_small(chunk - 1);
+        }
+        data
+    }
+
+    /// Merges the chunk at `at` with the following one if the result fits the target size.
+    fn merge_if_small(&mut self, at: usize) {
+        if self.chunks[at].len() + self.chunks[at + 1].len() <= Self::chunk_size(self.len) {
+            let mut next = self.chunks.remove(at + 1);
+            self.chunks[at].append_list(&mut next);
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.chunks.iter().flat_map(LinkedList::iter)
+    }
+}
+
+impl<T> From<LinkedList<T>> for IndexedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        let len = list.len();
+        if len == 0 {
+            return Self::new();
+        }
+        Self {
+            chunks: list.split_into(len.div_ceil(Self::chunk_size(len))),
+            len,
+        }
+    }
+}
+
+impl<T> From<IndexedList<T>> for LinkedList<T> {
+    fn from(list: IndexedList<T>) -> Self {
+        let mut chunks = list.chunks.into_iter().rev();
+        let mut merged = chunks.next().unwrap_or_default();
+        for mut chunk in chunks {
+            chunk.append_list(&mut merged);
+            merged = chunk;
+        }
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexedList;
+    use crate::testing::Rng;
+    use crate::LinkedList;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_conversions() {
+        let list: LinkedList<usize> = (0..1000).collect();
+        let indexed = IndexedList::from(list.clone());
+        assert_eq!(indexed.len(), 1000);
+        assert!(indexed.chunks.iter().all(|chunk| chunk.len() <= 64));
+        assert_eq!(indexed.get(0), Some(&0));
+        assert_eq!(indexed.get(999), Some(&999));
+        assert_eq!(indexed.get(1000), None);
+        let back = LinkedList::from(indexed);
+        assert_list_eq!(back, list);
+        back.debug_assert_len_consistent();
+
+        let empty = IndexedList::<i32>::from(LinkedList::new());
+        assert!(empty.is_empty());
+        assert_list_eq!(LinkedList::from(empty), []);
+    }
+
+    #[test]
+    fn test_against_list() {
+        let mut rng = Rng(0x1bad_b002);
+        let mut next = move || rng.next() as usize;
+        let mut indexed = IndexedList::new();
+        let mut plain = LinkedList::new();
+        for step in 0..5000 {
+            let len = plain.len();
+            match next() % 5 {
+                0 | 1 => {
+                    let at = next() % (len + 2);
+                    indexed.insert(step, at);
+                    plain.insert(step, at);
+                }
+                2 if len > 0 => {
+                    let at = next() % len;
+                    assert_eq!(indexed.remove(at), plain.iter().nth(at).copied());
+                    plain.remove(at);
+                }
+                3 if len > 0 => {
+                    let at = next() % len;
+                    *indexed.get_mut(at).unwrap() += 1;
+                    *plain.iter_mut().nth(at).unwrap() += 1;
+                }
+                _ => {
+                    indexed.push_back(step);
+                    plain.append(step);
+                }
+            }
+            let at = next() % (plain.len() + 1);
+            assert_eq!(indexed.get(at), plain.iter().nth(at));
+            assert_eq!(indexed.len(), plain.len());
+        }
+        let limit = 2 * IndexedList::<usize>::chunk_size(indexed.len());
+        assert!(indexed
+            .chunks
+            .iter()
+            .all(|chunk| (1..=limit).contains(&chunk.len())));
+        let values: Vec<usize> = indexed.iter().copied().collect();
+        assert_list_eq!(plain, values);
+
+        while indexed.len() > 100 {
+            let at = next() % indexed.len();
+            assert_eq!(indexed.remove(at), plain.iter().nth(at).copied());
+            plain.remove(at);
+        }
+        assert!(indexed.chunks.len() <= 2 * 100 / super::MIN_CHUNK + 1);
+        let values: Vec<usize> = indexed.iter().copied().collect();
+        assert_list_eq!(plain, values);
+        assert_list_eq!(LinkedList::from(indexed), values);
+    }
+}
@@ -0,0 +1,77 @@
+use core::cmp::Ordering;
+
+use crate::LinkedList;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Joined<T, U> {
+    Left(T),
+    Right(U),
+    Both(T, U),
+}
+
+impl<T> LinkedList<T> {
+    /// Joins two lists sorted by the same key, consuming both in a single pass.
+    ///
+    /// Elements comparing equal are paired in order, so `k` copies of a key on the left and `m`
+    /// on the right give `min(k, m)` `Both`s followed by the surplus from the longer side.
+    pub fn merge_join_by<U, F>(
+        mut self,
+        mut other: LinkedList<U>,
+        mut cmp: F,
+    ) -> LinkedList<Joined<T, U>>
+    where
+        F: FnMut(&T, &U) -> Ordering,
+    {
+        core::iter::from_fn(|| {
+            let order = match (self.first(), other.first()) {
+                (Some(a), Some(b)) => cmp(a, b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => return None,
+            };
+            Some(match order {
+                Ordering::Less => Joined::Left(self.pop_front()?),
+                Ordering::Greater => Joined::Right(other.pop_front()?),
+                Ordering::Equal => Joined::Both(self.pop_front()?, other.pop_front()?),
+            })
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Joined::{self, Both, Left, Right};
+    use crate::LinkedList;
+
+    #[test]
+    fn test_merge_join_by() {
+        let local = linked_list![(1, 'a'), (2, 'b'), (4, 'd')];
+        let remote = linked_list![2, 3, 4, 5];
+        let joined = local.merge_join_by(remote, |a, b| a.0.cmp(b));
+        assert_list_eq!(
+            joined,
+            [
+                Left((1, 'a')),
+                Both((2, 'b'), 2),
+                Right(3),
+                Both((4, 'd'), 4),
+                Right(5)
+            ]
+        );
+
+        let joined = linked_list![1, 1, 1, 2].merge_join_by(linked_list![1, 1, 2, 2], i32::cmp);
+        assert_list_eq!(
+            joined,
+            [Both(1, 1), Both(1, 1), Left(1), Both(2, 2), Right(2)]
+        );
+
+        let joined = LinkedList::new().merge_join_by(linked_list![1, 2], i32::cmp);
+        assert_list_eq!(joined, [Right(1), Right(2)]);
+        let joined = linked_list![1].merge_join_by(LinkedList::new(), i32::cmp);
+        assert_list_eq!(joined, [Left(1)]);
+        let joined: LinkedList<Joined<i32, i32>> =
+            LinkedList::new().merge_join_by(LinkedList::new(), i32::cmp);
+        assert_list_eq!(joined, []);
+    }
+}
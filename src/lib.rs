@@ -1,22 +1,92 @@
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(dropck_eyepatch))]
 
 extern crate alloc;
 
 use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::{Display, Formatter, Result};
-use core::ops::{Index, IndexMut};
+use core::ops::{Add, BitAnd, BitOr, Bound, Index, IndexMut, Range, RangeBounds, Sub};
+
+#[macro_export]
+macro_rules! linked_list {
+    () => {
+        $crate::LinkedList::new()
+    };
+    ($x:expr; $n:expr) => {
+        $crate::LinkedList::from_elem($x, $n)
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::LinkedList::from([$($x),+])
+    };
+}
+
+#[macro_export]
+macro_rules! assert_list_eq {
+    ($left:expr, [] $(,)?) => {
+        $crate::__assert_list_empty(&$left)
+    };
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_list_eq(&$left, &$right)
+    };
+}
+
+mod assert;
+#[cfg(any(feature = "arrayvec", feature = "heapless", feature = "smallvec"))]
+mod containers;
+mod cursor;
+mod diff;
+mod dst;
+mod handle;
+mod hash;
+#[cfg(feature = "hashbrown")]
+mod hashed;
+mod indexed;
+mod join;
+#[cfg(feature = "ufmt")]
+mod micro_fmt;
+mod parse;
+mod pinned;
+#[cfg(any(kani, test))]
+mod proofs;
+mod sequence;
+#[cfg(test)]
+mod testing;
+mod view;
+#[cfg(feature = "zeroize")]
+mod zeroizing;
+
+#[doc(hidden)]
+pub use assert::{assert_list_empty as __assert_list_empty, assert_list_eq as __assert_list_eq};
+pub use cursor::Cursor;
+pub use diff::Edit;
+pub use handle::{HandleList, NodeHandle};
+pub use hash::ByteHashableList;
+pub use indexed::IndexedList;
+pub use join::Joined;
+pub use parse::ParseListError;
+pub use pinned::PinnedList;
+pub use sequence::Sequence;
+pub use view::ListView;
+#[cfg(feature = "zeroize")]
+pub use zeroizing::ZeroizingList;
 
 #[derive(Debug, Clone)]
-pub struct LinkedList<T> {
+pub struct LinkedList<T: ?Sized> {
     head: Option<Box<Node<T>>>,
     len: usize,
 }
 
+/// `data` comes last and the layout is fixed, so that an unsized node can be allocated by
+/// hand; see [`dst`].
 #[derive(Debug, Clone)]
-struct Node<T> {
-    data: T,
+#[repr(C)]
+struct Node<T: ?Sized> {
     next: Option<Box<Node<T>>>,
+    data: T,
 }
 
 impl<T> Node<T> {
@@ -24,7 +94,9 @@ impl<T> Node<T> {
     fn new(data: T) -> Box<Self> {
         Box::new(Self { data, next: None })
     }
+}
 
+impl<T: ?Sized> Node<T> {
     #[inline]
     fn as_ref(&self) -> &T {
         &self.data
@@ -34,56 +106,22 @@ impl<T> Node<T> {
     fn as_mut(&mut self) -> &mut T {
         &mut self.data
     }
-
-    #[inline]
-    fn swap(&mut self, other: &mut Node<T>) {
-        core::mem::swap(&mut self.data, &mut other.data);
-    }
 }
 
-impl<T> LinkedList<T> {
+impl<T: ?Sized> LinkedList<T> {
     #[inline]
     pub fn new() -> Self {
         Self { head: None, len: 0 }
     }
 
-    pub fn append(&mut self, data: T) -> &mut Self {
-        let new_node = Node::new(data);
-        let mut ptr = &mut self.head;
-        while let Some(node) = ptr {
-            ptr = &mut node.next;
-        }
-        *ptr = Some(new_node);
-        self.len += 1;
-        self
-    }
-
-    pub fn prepend(&mut self, data: T) -> &mut Self {
-        let mut new_node = Node::new(data);
-        if self.head.is_some() {
-            let old = self.head.take();
-            new_node.next = old;
-        }
-        self.head = Some(new_node);
-        self.len += 1;
-        self
-    }
-
-    pub fn clear(&mut self) -> &mut Self {
-        while self.pop_front().is_some() {}
-        self.head = None;
-        self.len = 0;
-        self
+    pub fn len(&self) -> usize {
+        self.len
     }
 
     pub fn is_empty(&self) -> bool {
         self.head.is_none()
     }
 
-    pub fn len(&self) -> usize {
-        self.len
-    }
-
     pub fn first(&self) -> Option<&T> {
         if let Some(ref node) = self.head {
             Some(node.as_ref().as_ref())
@@ -130,20 +168,140 @@ impl<T> LinkedList<T> {
         Some(ptr.as_deref_mut().unwrap().as_mut())
     }
 
-    pub fn pop_front(&mut self) -> Option<T> {
-        if self.head.is_none() {
-            return None;
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            ptr: self.head.as_deref(),
+            len: self.len,
+        }
+    }
+
+    pub fn clear(&mut self) -> &mut Self {
+        self.drop_nodes();
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    /// Drops every node from the front. If an element's destructor panics, a guard carries on
+    /// with the remaining nodes during unwinding, so none of them leak.
+    fn drop_nodes(&mut self) {
+        struct Guard<'a, T: ?Sized>(&'a mut LinkedList<T>);
+
+        impl<T: ?Sized> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                self.0.drop_nodes();
+            }
+        }
+
+        while let Some(node) = self.pop_front_node() {
+            let guard = Guard(self);
+            drop(node);
+            core::mem::forget(guard);
+        }
+    }
+
+    fn pop_front_node(&mut self) -> Option<Box<Node<T>>> {
+        let mut node = self.head.take()?;
+        self.head = node.next.take();
+        self.len -= 1;
+        Some(node)
+    }
+
+    fn push_back_node(&mut self, node: Box<Node<T>>) {
+        let mut ptr = &mut self.head;
+        while let Some(node) = ptr {
+            ptr = &mut node.next;
+        }
+        *ptr = Some(node);
+        self.len += 1;
+    }
+
+    /// Panics if the stored length differs from the number of nodes, in debug builds only.
+    pub fn debug_assert_len_consistent(&self) {
+        #[cfg(debug_assertions)]
+        assert_len_consistent(&self.head, 0, self.len);
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Builds a list from `count` calls to `f`.
+    pub fn repeat_with<F: FnMut() -> T>(count: usize, f: F) -> Self {
+        core::iter::repeat_with(f).take(count).collect()
+    }
+
+    /// Builds a list of `n` copies of `elem`: `n - 1` clones followed by `elem` itself, which
+    /// is dropped if `n` is zero.
+    pub fn from_elem(elem: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        core::iter::repeat_n(elem, n).collect()
+    }
+
+    pub fn append(&mut self, data: T) -> &mut Self {
+        let new_node = Node::new(data);
+        let mut ptr = &mut self.head;
+        while let Some(node) = ptr {
+            ptr = &mut node.next;
+        }
+        *ptr = Some(new_node);
+        self.len += 1;
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    pub fn prepend(&mut self, data: T) -> &mut Self {
+        let mut new_node = Node::new(data);
+        if self.head.is_some() {
+            let old = self.head.take();
+            new_node.next = old;
+        }
+        self.head = Some(new_node);
+        self.len += 1;
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    /// Prepends the items as one chunk, keeping them in iterator order.
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) -> &mut Self {
+        let mut front = LinkedList::new();
+        let mut tail = &mut front.head;
+        for data in iter {
+            tail = &mut tail.insert(Node::new(data)).next;
+            front.len += 1;
         }
+        *tail = self.head.take();
+        front.len += core::mem::take(&mut self.len);
+        core::mem::swap(self, &mut front);
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    #[inline]
+    pub fn len_is(&self, n: usize) -> bool {
+        self.len == n
+    }
+
+    #[inline]
+    pub fn len_at_least(&self, n: usize) -> bool {
+        self.len >= n
+    }
+
+    #[inline]
+    pub fn len_at_most(&self, n: usize) -> bool {
+        self.len <= n
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.as_ref()?;
         let ptr = self.head.take().unwrap();
         self.head = ptr.next;
         self.len -= 1;
+        self.debug_assert_len_consistent();
         Some(ptr.data)
     }
 
     pub fn pop_last(&mut self) -> Option<T> {
-        if self.head.is_none() {
-            return None;
-        }
+        self.head.as_ref()?;
         if self.len == 1 {
             return self.pop_front();
         }
@@ -157,6 +315,7 @@ impl<T> LinkedList<T> {
         }
         let ptr = ptr.take().unwrap();
         self.len -= 1;
+        self.debug_assert_len_consistent();
         Some(ptr.data)
     }
 
@@ -181,20 +340,45 @@ impl<T> LinkedList<T> {
         new_node.next = ptr.as_deref_mut().unwrap().next.take();
         ptr.as_deref_mut().unwrap().next = Some(new_node);
         self.len += 1;
+        self.debug_assert_len_consistent();
         self
     }
 
+    /// Inserts like [`insert`](Self::insert) and returns a reference to the new element.
+    pub fn insert_mut(&mut self, data: T, index: usize) -> &mut T {
+        let index = index.min(self.len);
+        let mut ptr = &mut self.head;
+        for _ in 0..index {
+            ptr = &mut ptr.as_mut().unwrap().next;
+        }
+        let mut node = Node::new(data);
+        node.next = ptr.take();
+        *ptr = Some(node);
+        self.len += 1;
+        #[cfg(debug_assertions)]
+        assert_len_consistent(ptr, index, self.len);
+        &mut ptr.as_mut().unwrap().data
+    }
+
+    pub fn push_back_mut(&mut self, data: T) -> &mut T {
+        self.insert_mut(data, self.len)
+    }
+
+    pub fn push_front_mut(&mut self, data: T) -> &mut T {
+        self.insert_mut(data, 0)
+    }
+
     pub fn remove(&mut self, mut index: usize) -> &mut Self {
         if self.head.is_none() {
             return self;
         }
+        if index >= self.len {
+            index = self.len - 1;
+        }
         if index == 0 {
             self.pop_front();
             return self;
         }
-        if index >= self.len {
-            index = self.len - 1;
-        }
         let mut ptr = &mut self.head;
         for _ in 0..index - 1 {
             if let Some(node) = ptr {
@@ -211,6 +395,39 @@ impl<T> LinkedList<T> {
             .unwrap()
             .next
             .take();
+        self.len -= 1;
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    pub fn reversed(mut self) -> Self {
+        self.reverse();
+        self
+    }
+
+    pub fn sorted(mut self) -> Self
+    where
+        T: Ord,
+    {
+        self.sort();
+        self
+    }
+
+    /// Same as [`sorted`](Self::sorted).
+    pub fn into_sorted_linked_list(self) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        self.sorted()
+    }
+
+    pub fn with_appended(mut self, data: T) -> Self {
+        self.append(data);
+        self
+    }
+
+    pub fn with_prepended(mut self, data: T) -> Self {
+        self.prepend(data);
         self
     }
 
@@ -224,115 +441,1630 @@ impl<T> LinkedList<T> {
             node.next = self.head.take();
             self.head = Some(node);
         }
+        self.debug_assert_len_consistent();
         self
     }
 
-    pub fn iter(&self) -> Iter<'_, T> {
-        Iter {
-            ptr: self.head.as_ref(),
+    pub fn partition_in_place<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        let rest = core::mem::take(self);
+        let mut guard = Reassemble {
+            list: self,
+            parts: [LinkedList::new(), LinkedList::new(), rest],
+        };
+        let [front, back, rest] = &mut guard.parts;
+        let mut front_tail = &mut front.head;
+        let mut back_tail = &mut back.head;
+        while let Some(node) = rest.pop_front_node() {
+            if pred(&node.data) {
+                front_tail = &mut front_tail.insert(node).next;
+                front.len += 1;
+            } else {
+                back_tail = &mut back_tail.insert(node).next;
+                back.len += 1;
+            }
         }
+        let count = front.len;
+        *front_tail = back.head.take();
+        front.len += core::mem::take(&mut back.len);
+        core::mem::swap(guard.list, front);
+        guard.list.debug_assert_len_consistent();
+        count
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-        IterMut {
-            ptr: self.head.as_mut(),
+    /// Splits the list into `n` contiguous parts whose lengths differ by at most one.
+    /// If `n` is greater than the length, the trailing parts are empty.
+    pub fn split_into(mut self, n: usize) -> Vec<LinkedList<T>> {
+        assert!(n != 0, "cannot split a list into zero parts");
+        let (base, extra) = (self.len / n, self.len % n);
+        let mut parts = Vec::with_capacity(n);
+        let mut ptr = self.head.take();
+        self.len = 0;
+        for i in 0..n {
+            let len = base + usize::from(i < extra);
+            let mut part = LinkedList::new();
+            let mut tail = &mut part.head;
+            for _ in 0..len {
+                let mut node = ptr.take().unwrap();
+                ptr = node.next.take();
+                tail = &mut tail.insert(node).next;
+            }
+            part.len = len;
+            part.debug_assert_len_consistent();
+            parts.push(part);
         }
+        parts
     }
 
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter { ptr: self }
+    /// Merges sorted lists into one sorted list by relinking their nodes.
+    ///
+    /// Lists are merged pairwise in rounds, which takes O(n log k) time for `k` lists holding
+    /// `n` elements in total. Equal elements keep their input order, and elements from an
+    /// earlier list come before equal elements from a later one.
+    pub fn merge_all<I: IntoIterator<Item = LinkedList<T>>>(lists: I) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        let mut lists: Vec<LinkedList<T>> = lists.into_iter().collect();
+        while lists.len() > 1 {
+            let mut merged = Vec::with_capacity(lists.len().div_ceil(2));
+            let mut iter = lists.into_iter();
+            while let Some(mut a) = iter.next() {
+                if let Some(mut b) = iter.next() {
+                    a.merge_by(&mut b, &mut T::cmp);
+                }
+                merged.push(a);
+            }
+            lists = merged;
+        }
+        let list = lists.pop().unwrap_or_default();
+        list.debug_assert_len_consistent();
+        list
     }
-}
 
-pub struct Iter<'a, T> {
-    ptr: Option<&'a Box<Node<T>>>,
-}
+    /// Sorts the list with a stable merge sort that relinks nodes, in O(n log n) time.
+    pub fn sort(&mut self) -> &mut Self
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp)
+    }
 
-pub struct IterMut<'a, T> {
-    ptr: Option<&'a mut Box<Node<T>>>,
-}
+    /// Sorts the list with `cmp` like [`sort`](Self::sort). If `cmp` panics, the list keeps
+    /// all of its elements in an unspecified order.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut cmp: F) -> &mut Self {
+        self.merge_sort(&mut cmp);
+        self.debug_assert_len_consistent();
+        self
+    }
 
-pub struct IntoIter<T> {
-    ptr: LinkedList<T>,
-}
+    /// Sorts the list with a three-way quicksort over the node links that does not allocate.
+    ///
+    /// This is not stable: equal elements may be reordered. Each pivot is the median of the
+    /// first element and the two in the middle, so sorted and reversed input split evenly, and
+    /// every element equal to the pivot is set aside in the same pass. Only the smaller side of
+    /// each split is sorted recursively. After about `2 log n` levels the part that is left
+    /// falls back to the merge sort, so the worst case stays O(n log n).
+    ///
+    /// On distinct keys in random order this is slower than [`sort`](Self::sort); it pays off
+    /// when many elements compare equal.
+    pub fn sort_unstable(&mut self) -> &mut Self
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp)
+    }
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+    /// Sorts the list with `cmp` like [`sort_unstable`](Self::sort_unstable). If `cmp` panics,
+    /// the list keeps all of its elements in an unspecified order.
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut cmp: F) -> &mut Self {
+        let limit = 2 * (usize::BITS - self.len.leading_zeros()) as usize;
+        self.quicksort(&mut cmp, limit);
+        self.debug_assert_len_consistent();
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.ptr {
-            self.ptr = node.next.as_ref();
-            return Some(&node.data);
+    /// Sorts the list in place, splitting at most `limit` levels deep. Finished runs collect in
+    /// `front` and `back` around the part still being split, so the larger side of each split
+    /// is handled by the loop instead of by recursion. Parts of up to 32 elements go straight to
+    /// the merge sort.
+    fn quicksort<F>(&mut self, cmp: &mut F, mut limit: usize)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut guard = Reassemble {
+            list: self,
+            parts: [
+                LinkedList::new(),
+                LinkedList::new(),
+                LinkedList::new(),
+                LinkedList::new(),
+                LinkedList::new(),
+            ],
+        };
+        let Reassemble {
+            list: rest,
+            parts: [front, back, less, equal, greater],
+        } = &mut guard;
+        let mut front_tail = &mut front.head;
+        while rest.len > 32 && limit > 0 {
+            limit -= 1;
+            rest.partition_three_way(less, equal, greater, cmp);
+            if less.len <= greater.len {
+                less.quicksort(cmp, limit);
+                for part in [&mut *less, &mut *equal] {
+                    *front_tail = part.head.take();
+                    front.len += core::mem::take(&mut part.len);
+                    while let Some(node) = front_tail {
+                        front_tail = &mut node.next;
+                    }
+                }
+                core::mem::swap(*rest, greater);
+            } else {
+                greater.quicksort(cmp, limit);
+                greater.append_list(back);
+                equal.append_list(greater);
+                core::mem::swap(back, equal);
+                core::mem::swap(*rest, less);
+            }
         }
-        None
+        rest.merge_sort(cmp);
+        rest.append_list(back);
+        *front_tail = rest.head.take();
+        front.len += core::mem::take(&mut rest.len);
+        core::mem::swap(*rest, front);
     }
-}
 
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
+    /// Moves every element into `less`, `equal` or `greater` by comparing it with a pivot, the
+    /// median of the first element and the two in the middle. Needs at least three elements.
+    fn partition_three_way<F>(
+        &mut self,
+        less: &mut LinkedList<T>,
+        equal: &mut LinkedList<T>,
+        greater: &mut LinkedList<T>,
+        cmp: &mut F,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let back = self.detach_from(self.len / 2);
+        let mut guard = Reassemble {
+            list: self,
+            parts: [back, LinkedList::new()],
+        };
+        let Reassemble {
+            list: front,
+            parts: [back, pivot],
+        } = &mut guard;
+        let pivot_node = {
+            let [x, y, z] = [front.first(), back.first(), back.iter().nth(1)].map(Option::unwrap);
+            match median_of_three(x, y, z, cmp) {
+                0 => front.pop_front_node(),
+                1 => back.pop_front_node(),
+                _ => back.unlink_at(1),
+            }
+        };
+        pivot.push_front_node(pivot_node.unwrap());
+        let p = &pivot.head.as_ref().unwrap().data;
+        let mut less_tail = &mut less.head;
+        let mut equal_tail = &mut equal.head;
+        let mut greater_tail = &mut greater.head;
+        for rest in [&mut **front, back] {
+            while let Some(node) = &rest.head {
+                let order = cmp(&node.data, p);
+                let node = rest.pop_front_node().unwrap();
+                match order {
+                    Ordering::Less => {
+                        less_tail = &mut less_tail.insert(node).next;
+                        less.len += 1;
+                    }
+                    Ordering::Equal => {
+                        equal_tail = &mut equal_tail.insert(node).next;
+                        equal.len += 1;
+                    }
+                    Ordering::Greater => {
+                        greater_tail = &mut greater_tail.insert(node).next;
+                        greater.len += 1;
+                    }
+                }
+            }
+        }
+        equal.push_front_node(pivot.pop_front_node().unwrap());
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.ptr.take() {
-            self.ptr = node.next.as_mut();
-            return Some(&mut node.data);
+    fn merge_sort<F: FnMut(&T, &T) -> Ordering>(&mut self, cmp: &mut F) {
+        if self.len <= 1 {
+            return;
         }
-        None
+        let rest = self.detach_from(self.len / 2);
+        let mut guard = Reassemble {
+            list: self,
+            parts: [rest],
+        };
+        guard.list.merge_sort(cmp);
+        let [rest] = &mut guard.parts;
+        rest.merge_sort(cmp);
+        guard.list.merge_by(rest, cmp);
     }
-}
 
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
+    fn merge_by<F: FnMut(&T, &T) -> Ordering>(&mut self, other: &mut LinkedList<T>, cmp: &mut F) {
+        let a = core::mem::take(self);
+        let b = core::mem::take(other);
+        let mut guard = Reassemble {
+            list: self,
+            parts: [LinkedList::new(), a, b],
+        };
+        let [merged, a, b] = &mut guard.parts;
+        let mut tail = &mut merged.head;
+        while let (Some(x), Some(y)) = (&a.head, &b.head) {
+            let src = if cmp(&y.data, &x.data) == Ordering::Less {
+                &mut *b
+            } else {
+                &mut *a
+            };
+            tail = &mut tail.insert(src.pop_front_node().unwrap()).next;
+            merged.len += 1;
+        }
+        let rest = if a.head.is_some() { a } else { b };
+        *tail = rest.head.take();
+        merged.len += core::mem::take(&mut rest.len);
+        core::mem::swap(guard.list, merged);
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.ptr.head.take() {
-            self.ptr.head = node.next;
-            return Some(node.data);
+    fn append_list(&mut self, other: &mut LinkedList<T>) {
+        if other.head.is_none() {
+            return;
         }
-        None
+        let mut ptr = &mut self.head;
+        while let Some(node) = ptr {
+            ptr = &mut node.next;
+        }
+        *ptr = other.head.take();
+        self.len += core::mem::take(&mut other.len);
     }
-}
 
-impl<T> Index<usize> for LinkedList<T> {
-    type Output = T;
+    /// Inserts `data` before the first element matching `pred`, or appends it if none does.
+    /// Returns whether a matching element was found.
+    pub fn insert_before<F: FnMut(&T) -> bool>(&mut self, data: T, mut pred: F) -> bool {
+        let mut ptr = &mut self.head;
+        while ptr.as_ref().is_some_and(|node| !pred(&node.data)) {
+            ptr = &mut ptr.as_mut().unwrap().next;
+        }
+        let found = ptr.is_some();
+        let mut new_node = Node::new(data);
+        new_node.next = ptr.take();
+        *ptr = Some(new_node);
+        self.len += 1;
+        self.debug_assert_len_consistent();
+        found
+    }
 
-    fn index(&self, index: usize) -> &Self::Output {
-        let mut ptr = &self.head;
-        for _ in 0..index {
-            if let Some(node) = ptr {
-                ptr = &node.next;
-            } else {
-                break;
+    /// Inserts `data` after the last element matching `pred`, or prepends it if none does.
+    /// Returns whether a matching element was found.
+    pub fn insert_after<F: FnMut(&T) -> bool>(&mut self, data: T, mut pred: F) -> bool {
+        let mut last = None;
+        for (i, x) in self.iter().enumerate() {
+            if pred(x) {
+                last = Some(i);
+            }
+        }
+        match last {
+            Some(i) => {
+                self.insert(data, i + 1);
+                true
+            }
+            None => {
+                self.prepend(data);
+                false
             }
         }
-        ptr.as_deref().unwrap().as_ref()
     }
-}
 
-impl<T> IndexMut<usize> for LinkedList<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+    /// Removes and returns the first element matching `pred`.
+    pub fn remove_first<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<T> {
         let mut ptr = &mut self.head;
-        for _ in 0..index {
-            if let Some(node) = ptr {
-                ptr = &mut node.next;
-            } else {
-                break;
+        while ptr.as_ref().is_some_and(|node| !pred(&node.data)) {
+            ptr = &mut ptr.as_mut().unwrap().next;
+        }
+        let mut node = ptr.take()?;
+        *ptr = node.next.take();
+        self.len -= 1;
+        self.debug_assert_len_consistent();
+        Some(node.data)
+    }
+
+    /// Alias for [`remove_first`](LinkedList::remove_first).
+    #[inline]
+    pub fn take_first<F: FnMut(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        self.remove_first(pred)
+    }
+
+    /// Alias for [`remove_first`](LinkedList::remove_first).
+    #[inline]
+    pub fn pop_where<F: FnMut(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        self.remove_first(pred)
+    }
+
+    pub fn remove_last<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<T> {
+        let mut last = None;
+        for (i, x) in self.iter().enumerate() {
+            if pred(x) {
+                last = Some(i);
             }
         }
-        ptr.as_deref_mut().unwrap().as_mut()
+        let data = self.pop_at(last?);
+        self.debug_assert_len_consistent();
+        data
     }
-}
 
-impl<T: Display> Display for LinkedList<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        if self.head.is_none() {
-            write!(f, "None")?;
-        } else {
-            let mut ptr = &self.head;
+    fn pop_at(&mut self, index: usize) -> Option<T> {
+        self.unlink_at(index).map(|node| node.data)
+    }
+
+    fn unlink_at(&mut self, index: usize) -> Option<Box<Node<T>>> {
+        let mut ptr = &mut self.head;
+        for _ in 0..index {
+            ptr = &mut ptr.as_mut()?.next;
+        }
+        let mut node = ptr.take()?;
+        *ptr = node.next.take();
+        self.len -= 1;
+        Some(node)
+    }
+
+    fn push_front_node(&mut self, mut node: Box<Node<T>>) {
+        node.next = self.head.take();
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    fn detach_from(&mut self, at: usize) -> LinkedList<T> {
+        let mut ptr = &mut self.head;
+        for _ in 0..at {
+            match ptr {
+                Some(node) => ptr = &mut node.next,
+                None => return LinkedList::new(),
+            }
+        }
+        let rest = LinkedList {
+            head: ptr.take(),
+            len: self.len - at,
+        };
+        self.len = at;
+        rest
+    }
+
+    pub fn move_to_front(&mut self, index: usize) -> &mut Self {
+        if index == 0 {
+            return self;
+        }
+        if let Some(node) = self.unlink_at(index) {
+            self.push_front_node(node);
+        }
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    /// Removes every element matching `pred` and returns how many were removed.
+    pub fn remove_all<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        self.remove_indexed(|_, x| pred(x))
+    }
+
+    /// Keeps the elements at indices `offset`, `offset + n`, `offset + 2n`, ... and removes the
+    /// rest.
+    pub fn keep_every_nth(&mut self, n: usize, offset: usize) -> &mut Self {
+        assert!(n != 0, "n must be greater than zero");
+        self.remove_indexed(|i, _| i < offset || !(i - offset).is_multiple_of(n));
+        self
+    }
+
+    /// Removes the elements at indices `offset`, `offset + n`, `offset + 2n`, ... and keeps the
+    /// rest.
+    pub fn remove_every_nth(&mut self, n: usize, offset: usize) -> &mut Self {
+        assert!(n != 0, "n must be greater than zero");
+        self.remove_indexed(|i, _| i >= offset && (i - offset).is_multiple_of(n));
+        self
+    }
+
+    /// Returns a copy of the elements at indices `0`, `n`, `2n`, ...
+    pub fn take_every(&self, n: usize) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        assert!(n != 0, "n must be greater than zero");
+        self.iter().step_by(n).cloned().collect()
+    }
+
+    /// Returns a copy of the list without the elements at indices `0`, `n`, `2n`, ...
+    pub fn drop_every(&self, n: usize) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        assert!(n != 0, "n must be greater than zero");
+        self.iter()
+            .enumerate()
+            .filter(|(i, _)| !i.is_multiple_of(n))
+            .map(|(_, x)| x.clone())
+            .collect()
+    }
+
+    fn remove_indexed<F: FnMut(usize, &T) -> bool>(&mut self, mut pred: F) -> usize {
+        let mut removed = 0;
+        let mut index = 0;
+        let mut ptr = &mut self.head;
+        while let Some(node) = ptr {
+            if pred(index, &node.data) {
+                let mut node = ptr.take().unwrap();
+                *ptr = node.next.take();
+                self.len -= 1;
+                removed += 1;
+            } else {
+                ptr = &mut ptr.as_mut().unwrap().next;
+            }
+            index += 1;
+        }
+        self.debug_assert_len_consistent();
+        removed
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`, assuming the
+    /// list is partitioned so that all elements matching `pred` come first.
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().take_while(|x| pred(x)).count()
+    }
+
+    /// Returns the index range of the elements equal to `x` in a sorted list, or the empty
+    /// range at the insertion point if there are none. Stops at the first greater element.
+    pub fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord,
+    {
+        self.equal_range_by(|data| data.cmp(x))
+    }
+
+    /// Like [`equal_range`](Self::equal_range), with `f` comparing each element to the target.
+    pub fn equal_range_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Range<usize> {
+        let (mut start, mut end) = (0, 0);
+        for data in self {
+            match f(data) {
+                Ordering::Less => start += 1,
+                Ordering::Equal => {}
+                Ordering::Greater => break,
+            }
+            end += 1;
+        }
+        start..end
+    }
+
+    pub fn equal_range_by_key<K: Ord, F: FnMut(&T) -> K>(&self, key: &K, mut f: F) -> Range<usize> {
+        self.equal_range_by(|data| f(data).cmp(key))
+    }
+
+    pub fn move_to_back(&mut self, index: usize) -> &mut Self {
+        if index >= self.len.saturating_sub(1) {
+            return self;
+        }
+        if let Some(node) = self.unlink_at(index) {
+            self.push_back_node(node);
+        }
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    /// Moves the elements in `range` so that the block starts at index `dest`, keeping the order
+    /// inside and outside the block.
+    ///
+    /// `dest` is an index into the list *after* the block is taken out, so it must be at most
+    /// `len - range.len()`: `move_range(0..2, 1)` on `[a, b, c, d]` gives `[c, a, b, d]`.
+    pub fn move_range<R: RangeBounds<usize>>(&mut self, range: R, dest: usize) -> &mut Self {
+        let range = resolve_range(range, self.len);
+        let remaining = self.len - range.len();
+        assert!(
+            dest <= remaining,
+            "destination {} out of range for the {} elements outside the block",
+            dest,
+            remaining
+        );
+        let mut rest = self.detach_from(range.end);
+        let mut block = self.detach_from(range.start);
+        self.append_list(&mut rest);
+        let mut rest = self.detach_from(dest);
+        self.append_list(&mut block);
+        self.append_list(&mut rest);
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    /// Moves the elements in `range` into `dest` so that the first of them lands at index `at`,
+    /// relinking nodes without moving or dropping any element. Panics if `range` is out of
+    /// bounds for `self` or `at > dest.len()`.
+    ///
+    /// Both lists are borrowed mutably, so a transfer within one list is rejected at compile
+    /// time; use [`move_range`](Self::move_range) for that:
+    ///
+    /// ```compile_fail,E0499
+    /// use safety_linkedlist::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = (0..4).collect();
+    /// list.transfer(0..2, &mut list, 1);
+    /// ```
+    pub fn transfer<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        dest: &mut LinkedList<T>,
+        at: usize,
+    ) -> &mut Self {
+        let range = resolve_range(range, self.len);
+        assert!(
+            at <= dest.len,
+            "destination {} out of range for list of length {}",
+            at,
+            dest.len
+        );
+        let mut rest = self.detach_from(range.end);
+        let mut block = self.detach_from(range.start);
+        self.append_list(&mut rest);
+        let mut rest = dest.detach_from(at);
+        dest.append_list(&mut block);
+        dest.append_list(&mut rest);
+        self.debug_assert_len_consistent();
+        dest.debug_assert_len_consistent();
+        self
+    }
+
+    /// Reorders the list so that position `i` holds the element previously at `perm[i]`,
+    /// by relinking nodes. Panics unless `perm` is a permutation of `0..len`.
+    pub fn permute(&mut self, perm: &[usize]) -> &mut Self {
+        assert!(
+            perm.len() == self.len,
+            "permutation of length {} for list of length {}",
+            perm.len(),
+            self.len
+        );
+        let mut seen = alloc::vec![false; self.len];
+        for &index in perm {
+            assert!(
+                index < self.len,
+                "permutation index {} out of range for list of length {}",
+                index,
+                self.len
+            );
+            assert!(
+                !core::mem::replace(&mut seen[index], true),
+                "permutation index {} appears more than once",
+                index
+            );
+        }
+        let mut nodes = Vec::with_capacity(self.len);
+        while let Some(node) = self.pop_front_node() {
+            nodes.push(Some(node));
+        }
+        for &index in perm.iter().rev() {
+            self.push_front_node(nodes[index].take().unwrap());
+        }
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    pub fn trim_start_matches<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> &mut Self {
+        while self.first().is_some_and(&mut pred) {
+            self.pop_front();
+        }
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    pub fn trim_end_matches<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> &mut Self {
+        let mut keep = 0;
+        for (i, x) in self.iter().enumerate() {
+            if !pred(x) {
+                keep = i + 1;
+            }
+        }
+        self.detach_from(keep);
+        self.debug_assert_len_consistent();
+        self
+    }
+
+    pub fn trim_matches<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> &mut Self {
+        self.trim_start_matches(&mut pred).trim_end_matches(pred)
+    }
+
+    /// Sorts the list with bubble sort by swapping adjacent nodes.
+    ///
+    /// Each swap relinks the two nodes and the elements never move, so every element keeps its
+    /// address; [`PinnedList`] relies on no operation moving an element between nodes. This
+    /// takes O(n²) time and is provided for educational purposes.
+    pub fn bubble_sort(&mut self)
+    where
+        T: Ord,
+    {
+        for end in (1..self.len).rev() {
+            let mut swapped = false;
+            let mut ptr = &mut self.head;
+            for _ in 0..end {
+                let Some(node) = ptr else { break };
+                if node.next.as_ref().is_some_and(|next| node.data > next.data) {
+                    let mut node = ptr.take().unwrap();
+                    let mut next = node.next.take().unwrap();
+                    node.next = next.next.take();
+                    next.next = Some(node);
+                    *ptr = Some(next);
+                    swapped = true;
+                }
+                ptr = &mut ptr.as_mut().unwrap().next;
+            }
+            if !swapped {
+                break;
+            }
+        }
+        self.debug_assert_len_consistent();
+    }
+
+    /// Counts the occurrences of each distinct element, in ascending order of the elements.
+    pub fn counts_sorted(&self) -> LinkedList<(T, usize)>
+    where
+        T: Ord + Clone,
+    {
+        let mut items: Vec<&T> = self.iter().collect();
+        items.sort();
+        let mut counts = LinkedList::new();
+        let mut tail = &mut counts.head;
+        let mut iter = items.into_iter().peekable();
+        while let Some(item) = iter.next() {
+            let mut count = 1;
+            while iter.next_if(|&next| next == item).is_some() {
+                count += 1;
+            }
+            tail = &mut tail.insert(Node::new((item.clone(), count))).next;
+            counts.len += 1;
+        }
+        counts
+    }
+
+    /// Returns the sums of every window of `window_size` consecutive elements, keeping a
+    /// running sum so that the whole computation is O(n).
+    pub fn window_sum(&self, window_size: usize) -> LinkedList<T>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Copy + Default,
+    {
+        assert!(window_size != 0, "window size must be greater than zero");
+        if window_size > self.len {
+            return LinkedList::new();
+        }
+        let mut lead = self.iter();
+        let mut sum = lead
+            .by_ref()
+            .take(window_size)
+            .fold(T::default(), |acc, &x| acc + x);
+        let rest = lead.zip(self.iter()).map(move |(&new, &old)| {
+            sum = sum - old + new;
+            sum
+        });
+        core::iter::once(sum).chain(rest).collect()
+    }
+
+    /// Merges runs of adjacent elements: when `merge` returns `Ok` the merged element is tried
+    /// against the next neighbour, and on `Err((a, b))` `a` is kept and `b` carries on.
+    pub fn coalesce<F>(&mut self, mut merge: F)
+    where
+        F: FnMut(T, T) -> core::result::Result<T, (T, T)>,
+    {
+        let rest = core::mem::take(self);
+        let mut guard = Reassemble {
+            list: self,
+            parts: [LinkedList::new(), rest],
+        };
+        let [front, rest] = &mut guard.parts;
+        let mut current = match rest.pop_front_node() {
+            Some(node) => node.data,
+            None => return,
+        };
+        let mut tail = &mut front.head;
+        while let Some(mut node) = rest.pop_front_node() {
+            current = match merge(current, node.data) {
+                Ok(merged) => merged,
+                Err((kept, next)) => {
+                    node.data = kept;
+                    tail = &mut tail.insert(node).next;
+                    front.len += 1;
+                    next
+                }
+            };
+        }
+        *tail = Some(Node::new(current));
+        front.len += 1;
+        core::mem::swap(guard.list, front);
+        guard.list.debug_assert_len_consistent();
+    }
+
+    /// Returns the prefix sums of the list.
+    pub fn cumsum(&self) -> LinkedList<T>
+    where
+        T: Add<Output = T> + Copy + Default,
+    {
+        self.iter()
+            .scan(T::default(), |sum, &x| {
+                *sum = *sum + x;
+                Some(*sum)
+            })
+            .collect()
+    }
+
+    /// Returns the running maximum of the list. Incomparable elements keep the previous maximum.
+    pub fn cummax(&self) -> LinkedList<T>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.running(|best, x| x > best)
+    }
+
+    /// Returns the running minimum of the list. Incomparable elements keep the previous minimum.
+    pub fn cummin(&self) -> LinkedList<T>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.running(|best, x| x < best)
+    }
+
+    fn running<F: FnMut(&T, &T) -> bool>(&self, mut replaces: F) -> LinkedList<T>
+    where
+        T: Copy,
+    {
+        let mut best = None;
+        self.iter()
+            .map(|&x| match best {
+                Some(b) if !replaces(&b, &x) => b,
+                _ => {
+                    best = Some(x);
+                    x
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the differences between consecutive elements, one shorter than the list.
+    pub fn difference_list(&self) -> LinkedList<T>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .map(|(&prev, &next)| next - prev)
+            .collect()
+    }
+
+    pub fn scan_with_index<B, F: FnMut(usize, &T) -> B>(&self, mut f: F) -> LinkedList<B> {
+        self.iter().enumerate().map(|(i, x)| f(i, x)).collect()
+    }
+
+    /// Sums consecutive pairs of elements. For an odd length the last element has no partner
+    /// and is left out of the result.
+    pub fn sum_pairs(&self) -> LinkedList<T>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        let mut iter = self.iter();
+        core::iter::from_fn(|| Some(iter.next()?.clone() + iter.next()?.clone())).collect()
+    }
+
+    /// Returns the index of the element `elem` points to, comparing addresses rather than
+    /// values, or `None` if it does not point into this list.
+    pub fn index_of_ref(&self, elem: &T) -> Option<usize> {
+        self.iter().position(|x| core::ptr::eq(x, elem))
+    }
+
+    /// Like `iter().enumerate()`, but counting from `start`.
+    pub fn enumerate_from(&self, start: usize) -> EnumerateFrom<'_, T> {
+        EnumerateFrom {
+            iter: self.iter(),
+            index: start,
+        }
+    }
+
+    /// Borrows the first `N` elements, or returns `None` if the list is shorter.
+    pub fn first_array<const N: usize>(&self) -> Option<[&T; N]> {
+        if self.len < N {
+            return None;
+        }
+        let mut iter = self.iter();
+        Some(core::array::from_fn(|_| iter.next().unwrap()))
+    }
+
+    /// Mutably borrows the first `N` elements, or returns `None` if the list is shorter.
+    pub fn first_array_mut<const N: usize>(&mut self) -> Option<[&mut T; N]> {
+        if self.len < N {
+            return None;
+        }
+        let mut iter = self.iter_mut();
+        Some(core::array::from_fn(|_| iter.next().unwrap()))
+    }
+
+    /// Overwrites the elements starting at `dest` with clones of the elements in `src`, like
+    /// `slice::copy_within`. The source is cloned in full before anything is written, so
+    /// overlapping ranges copy the original values.
+    pub fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize)
+    where
+        T: Clone,
+    {
+        let src = resolve_range(src, self.len);
+        assert!(
+            dest <= self.len - src.len(),
+            "dest {} out of range for copying {} elements in a list of length {}",
+            dest,
+            src.len(),
+            self.len
+        );
+        let copies: Vec<T> = self
+            .iter()
+            .skip(src.start)
+            .take(src.len())
+            .cloned()
+            .collect();
+        for (slot, data) in self.iter_mut().skip(dest).zip(copies) {
+            *slot = data;
+        }
+    }
+
+    pub fn find_map<U, F: FnMut(&T) -> Option<U>>(&self, f: F) -> Option<U> {
+        self.iter().find_map(f)
+    }
+
+    /// Calls `f` on each element until it returns `false`, and returns the index of the element
+    /// it stopped at, or the length if it never did.
+    pub fn for_each_while<F: FnMut(&T) -> bool>(&self, mut f: F) -> usize {
+        self.iter().position(|x| !f(x)).unwrap_or(self.len)
+    }
+
+    /// Moves every element into the list for its key, keeping the original order within each
+    /// list. The nodes are relinked, not reallocated.
+    pub fn group_to_btree_map<K: Ord, F: FnMut(&T) -> K>(
+        self,
+        key: F,
+    ) -> BTreeMap<K, LinkedList<T>> {
+        let mut groups = BTreeMap::new();
+        self.group_into(&mut groups, key, |groups, key| {
+            groups.entry(key).or_insert_with(Group::new)
+        });
+        groups
+            .into_iter()
+            .map(|(key, group)| (key, group.list))
+            .collect()
+    }
+
+    /// Links every node onto the tail of the group that `group` finds or creates in `groups`
+    /// for its key, so each group keeps the original order.
+    fn group_into<K, M, F, G>(mut self, groups: &mut M, mut key: F, mut group: G)
+    where
+        F: FnMut(&T) -> K,
+        G: FnMut(&mut M, K) -> &mut Group<T>,
+    {
+        while let Some(node) = &self.head {
+            let key = key(&node.data);
+            let node = self.pop_front_node().unwrap();
+            group(groups, key).push_back_node(node);
+        }
+    }
+
+    /// Returns whether `seq` appears as a contiguous run in the list. Takes O(n m) time.
+    pub fn contains_sequence(&self, seq: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.find_subsequence_slice(seq).is_some()
+    }
+
+    /// Returns the index where `needle` first appears as a contiguous run, `Some(0)` for an
+    /// empty needle like `str::find("")`. Takes O(n m) time.
+    pub fn find_subsequence(&self, needle: &LinkedList<T>) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.find_run(needle.iter())
+    }
+
+    pub fn find_subsequence_slice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.find_run(needle.iter())
+    }
+
+    pub fn contains_subsequence(&self, needle: &LinkedList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.find_subsequence(needle).is_some()
+    }
+
+    /// Replaces the first run equal to `needle` with `replacement`, relinking the nodes around
+    /// it. Returns whether a run was found; an empty needle matches at the front.
+    pub fn replace_subsequence<I>(&mut self, needle: &[T], replacement: I) -> bool
+    where
+        I: IntoIterator<Item = T>,
+        T: PartialEq,
+    {
+        let Some(at) = self.find_subsequence_slice(needle) else {
+            return false;
+        };
+        let mut rest = self.detach_from(at + needle.len());
+        drop(self.detach_from(at));
+        self.extend(replacement);
+        self.append_list(&mut rest);
+        self.debug_assert_len_consistent();
+        true
+    }
+
+    /// Replaces every non-overlapping run equal to `needle`, scanning left to right and
+    /// resuming after each replacement, and returns how many were replaced. Like
+    /// `str::replace`, an empty needle matches before every element and at the end.
+    pub fn replace_all_subsequences<I>(&mut self, needle: &[T], replacement: I) -> usize
+    where
+        I: IntoIterator<Item = T> + Clone,
+        T: PartialEq,
+    {
+        let mut rest = core::mem::take(self);
+        let mut tail = &mut self.head;
+        let mut count = 0;
+        while let Some(at) = rest.find_subsequence_slice(needle) {
+            for _ in 0..at {
+                tail = &mut tail.insert(rest.pop_front_node().unwrap()).next;
+                self.len += 1;
+            }
+            for _ in 0..needle.len() {
+                rest.pop_front_node();
+            }
+            for data in replacement.clone() {
+                tail = &mut tail.insert(Node::new(data)).next;
+                self.len += 1;
+            }
+            count += 1;
+            if needle.is_empty() {
+                match rest.pop_front_node() {
+                    Some(node) => tail = &mut tail.insert(node).next,
+                    None => break,
+                }
+                self.len += 1;
+            }
+        }
+        *tail = rest.head.take();
+        self.len += core::mem::take(&mut rest.len);
+        self.debug_assert_len_consistent();
+        count
+    }
+
+    fn find_run<'b, I>(&self, needle: I) -> Option<usize>
+    where
+        I: ExactSizeIterator<Item = &'b T> + Clone,
+        T: PartialEq + 'b,
+    {
+        let mut view = self.as_view();
+        let mut index = 0;
+        while view.len() >= needle.len() {
+            if view.iter().take(needle.len()).eq(needle.clone()) {
+                return Some(index);
+            }
+            view = view.split_first()?.1;
+            index += 1;
+        }
+        None
+    }
+
+    /// Iterates over the elements along with whether each is the first, the last or the only
+    /// one. The remaining length is known, so no lookahead is needed.
+    pub fn iter_with_position(&self) -> WithPosition<'_, T> {
+        WithPosition {
+            iter: self.iter(),
+            first: true,
+        }
+    }
+
+    /// Borrows the list in successive chunks of `size` elements, the last one possibly shorter,
+    /// in a single traversal. Panics if `size` is zero.
+    pub fn iter_chunks(&self, size: usize) -> IterChunks<'_, T> {
+        assert!(size > 0, "chunk size must be greater than zero");
+        IterChunks {
+            iter: self.iter(),
+            size,
+        }
+    }
+
+    pub fn starts_with(&self, prefix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        prefix.len() <= self.len && self.iter().take(prefix.len()).eq(prefix)
+    }
+
+    /// Walks to the last `suffix.len()` elements and compares them with `suffix`.
+    pub fn ends_with(&self, suffix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        suffix.len() <= self.len && self.iter().skip(self.len - suffix.len()).eq(suffix)
+    }
+
+    /// Returns the element with the largest key. Of several equal maxima the first wins.
+    pub fn max_by_key_mut<K: Ord, F: FnMut(&T) -> K>(&mut self, key: F) -> Option<&mut T> {
+        self.extreme_by_key_mut(key, Ordering::Greater)
+    }
+
+    /// Returns the element with the smallest key. Of several equal minima the first wins.
+    pub fn min_by_key_mut<K: Ord, F: FnMut(&T) -> K>(&mut self, key: F) -> Option<&mut T> {
+        self.extreme_by_key_mut(key, Ordering::Less)
+    }
+
+    /// Finds the index of the extreme element in one walk, then walks again to borrow it.
+    fn extreme_by_key_mut<K: Ord, F: FnMut(&T) -> K>(
+        &mut self,
+        mut key: F,
+        better: Ordering,
+    ) -> Option<&mut T> {
+        let mut best: Option<(usize, K)> = None;
+        for (index, data) in self.iter().enumerate() {
+            let key = key(data);
+            if best
+                .as_ref()
+                .is_none_or(|(_, best)| key.cmp(best) == better)
+            {
+                best = Some((index, key));
+            }
+        }
+        let (index, _) = best?;
+        self.iter_mut().nth(index)
+    }
+
+    /// Removes the leading elements matching `pred` and returns them as a list.
+    pub fn drain_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> LinkedList<T> {
+        let count = self.iter().take_while(|x| pred(x)).count();
+        let rest = self.detach_from(count);
+        let drained = core::mem::replace(self, rest);
+        self.debug_assert_len_consistent();
+        drained.debug_assert_len_consistent();
+        drained
+    }
+
+    /// Returns the indices of the elements in ascending order, leaving the list untouched.
+    /// Equal elements keep their relative order.
+    pub fn sort_indices(&self) -> Vec<usize>
+    where
+        T: Ord,
+    {
+        self.sort_indices_by(T::cmp)
+    }
+
+    pub fn sort_indices_by<F: FnMut(&T, &T) -> Ordering>(&self, mut cmp: F) -> Vec<usize> {
+        let mut pairs: Vec<(usize, &T)> = self.iter().enumerate().collect();
+        pairs.sort_by(|a, b| cmp(a.1, b.1));
+        pairs.into_iter().map(|(index, _)| index).collect()
+    }
+
+    pub fn sort_indices_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut key: F) -> Vec<usize> {
+        self.sort_indices_by(|a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Returns `true` if every element equals the first; empty and single-element lists are
+    /// trivially all equal.
+    pub fn all_equal(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut iter = self.iter();
+        match iter.next() {
+            Some(first) => iter.all(|x| x == first),
+            None => true,
+        }
+    }
+
+    /// Returns `true` if `other` holds every element of `self`, counting duplicates: an element
+    /// appearing `k` times here must appear at least `k` times in `other`. O(n·m); with the
+    /// `hashbrown` feature, `is_subset_hashed` takes O(n + m) for hashable elements.
+    pub fn is_subset(&self, other: &LinkedList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        if self.len > other.len {
+            return false;
+        }
+        let mut used = alloc::vec![false; other.len];
+        self.iter().all(|x| {
+            let found = other
+                .iter()
+                .zip(used.iter_mut())
+                .find(|(y, used)| !**used && x == *y);
+            found.map(|(_, used)| *used = true).is_some()
+        })
+    }
+
+    /// Returns `true` if no element of `self` equals an element of `other`. O(n·m); with the
+    /// `hashbrown` feature, `is_disjoint_hashed` takes O(n + m) for hashable elements.
+    pub fn is_disjoint(&self, other: &LinkedList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        !self.iter().any(|x| other.iter().any(|y| x == y))
+    }
+
+    /// Returns `true` if no two elements are equal, stopping at the first repeat. O(n log n).
+    pub fn all_unique(&self) -> bool
+    where
+        T: Ord,
+    {
+        let mut seen = BTreeSet::new();
+        self.iter().all(|x| seen.insert(x))
+    }
+
+    /// Returns the median element, the lower of the two middle ones for an even length.
+    /// Collects and sorts references, so O(n log n).
+    pub fn median(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let mut refs: Vec<&T> = self.iter().collect();
+        refs.sort_unstable();
+        refs.get(self.len.checked_sub(1)? / 2).copied()
+    }
+
+    /// Calls `f` on each adjacent pair `(list[i], list[i + 1])` with mutable access to both.
+    /// Lists shorter than two make no calls.
+    pub fn for_each_pair_mut<F: FnMut(&mut T, &mut T)>(&mut self, mut f: F) {
+        let mut ptr = self.head.as_deref_mut();
+        while let Some(Node { data, next }) = ptr {
+            if let Some(node) = next.as_deref_mut() {
+                f(data, &mut node.data);
+            }
+            ptr = next.as_deref_mut();
+        }
+        self.debug_assert_len_consistent();
+    }
+
+    /// Returns the `n`th smallest element, counting from zero, by partially sorting
+    /// references to the elements. Returns `None` if `n >= len`.
+    pub fn nth_smallest(&self, n: usize) -> Option<&T>
+    where
+        T: Ord,
+    {
+        if n >= self.len {
+            return None;
+        }
+        let mut refs: Vec<&T> = self.iter().collect();
+        Some(*refs.select_nth_unstable(n).1)
+    }
+
+    /// Refills the list from `items`, reusing the existing nodes in order: leftover nodes are
+    /// dropped and new ones allocated only for items past the current length. Returns the new
+    /// length.
+    pub fn overwrite_from<I: IntoIterator<Item = T>>(&mut self, items: I) -> usize {
+        let mut items = items.into_iter();
+        let mut written = 0;
+        for (slot, data) in self.iter_mut().zip(&mut items) {
+            *slot = data;
+            written += 1;
+        }
+        if written < self.len {
+            drop(self.detach_from(written));
+        } else {
+            self.extend(items);
+        }
+        self.debug_assert_len_consistent();
+        self.len
+    }
+
+    /// Counts the occurrences of each distinct element. O(n log n).
+    pub fn frequency_map(&self) -> BTreeMap<&T, usize>
+    where
+        T: Ord,
+    {
+        let mut counts = BTreeMap::new();
+        for data in self {
+            *counts.entry(data).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns the most frequent element, the smallest one among ties.
+    pub fn mode(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let mut best = None;
+        for (data, count) in self.frequency_map() {
+            if best.is_none_or(|(_, most)| count > most) {
+                best = Some((data, count));
+            }
+        }
+        best.map(|(data, _)| data)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            ptr: self.head.as_mut(),
+            len: self.len,
+        }
+    }
+
+    /// Splits the list into mutable iterators over `[0, index)` and `[index, len)`, like
+    /// `slice::split_at_mut`. Panics if `index > len`.
+    pub fn split_mut_at(&mut self, index: usize) -> (IterMut<'_, T>, IterMut<'_, T>) {
+        assert!(
+            index <= self.len,
+            "split index {index} out of range for length {}",
+            self.len
+        );
+        self.debug_assert_len_consistent();
+        let mut link = &mut self.head;
+        for _ in 0..index {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        let back: *mut Option<Box<Node<T>>> = link;
+        let front = IterMut {
+            ptr: if index == 0 { None } else { self.head.as_mut() },
+            len: index,
+        };
+        // SAFETY: `back` points at the `next` link of the node at `index - 1` (or at `head` when
+        // `index == 0`, in which case `front` holds nothing). `front` stops after `index`
+        // elements without touching the `next` link of its last node, so the two iterators
+        // never reach the same memory and both borrow from `self` for the same lifetime.
+        let back = IterMut {
+            ptr: unsafe { (*back).as_mut() },
+            len: self.len - index,
+        };
+        (front, back)
+    }
+}
+
+/// Sorts by `total_cmp`: negative NaNs first, then `-∞ < … < -0.0 < 0.0 < … < ∞`, then
+/// positive NaNs, so every value has a place and `-0.0` and `0.0` never compare equal.
+/// Stable, relinking nodes like [`sort`](LinkedList::sort).
+impl LinkedList<f32> {
+    pub fn sort_floats(&mut self) -> &mut Self {
+        self.sort_by(f32::total_cmp)
+    }
+}
+
+impl LinkedList<f64> {
+    pub fn sort_floats(&mut self) -> &mut Self {
+        self.sort_by(f64::total_cmp)
+    }
+}
+
+impl<T: ?Sized> Default for LinkedList<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { ptr: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Counts the nodes reachable from `link`, adds the `before` nodes ahead of it and panics if
+/// the total differs from the stored `len`.
+#[cfg(debug_assertions)]
+fn assert_len_consistent<T: ?Sized>(mut link: &Option<Box<Node<T>>>, before: usize, len: usize) {
+    let mut count = before;
+    while let Some(node) = link {
+        count += 1;
+        link = &node.next;
+    }
+    assert!(
+        count == len,
+        "stored length {} differs from the {} nodes in the list",
+        len,
+        count
+    );
+}
+
+/// Returns which of `x`, `y` and `z` is the median, as 0, 1 or 2.
+fn median_of_three<T, F>(x: &T, y: &T, z: &T, cmp: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if cmp(x, y) == Ordering::Less {
+        if cmp(y, z) != Ordering::Greater {
+            1
+        } else if cmp(x, z) == Ordering::Less {
+            2
+        } else {
+            0
+        }
+    } else if cmp(x, z) != Ordering::Greater {
+        0
+    } else if cmp(y, z) == Ordering::Less {
+        2
+    } else {
+        1
+    }
+}
+
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).expect("range start overflows usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).expect("range end overflows usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end,
+        "range starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end {} out of range for list of length {}",
+        end,
+        len
+    );
+    start..end
+}
+
+/// Links the detached parts back onto the list when dropped, so that the list stays
+/// consistent if a user closure panics while its nodes are being moved around.
+struct Reassemble<'a, T, const N: usize> {
+    list: &'a mut LinkedList<T>,
+    parts: [LinkedList<T>; N],
+}
+
+impl<T, const N: usize> Drop for Reassemble<'_, T, N> {
+    fn drop(&mut self) {
+        for part in self.parts.iter_mut() {
+            self.list.append_list(part);
+        }
+    }
+}
+
+/// A list being filled by [`group_into`](LinkedList::group_into), with the `next` link of its
+/// last node so that appending takes O(1).
+struct Group<T> {
+    list: LinkedList<T>,
+    tail: *mut Option<Box<Node<T>>>,
+}
+
+impl<T> Group<T> {
+    fn new() -> Self {
+        Group {
+            list: LinkedList::new(),
+            tail: core::ptr::null_mut(),
+        }
+    }
+
+    fn push_back_node(&mut self, node: Box<Node<T>>) {
+        let link = match self.list.len {
+            0 => &mut self.list.head,
+            1 => &mut self.list.head.as_mut().unwrap().next,
+            // SAFETY: from the second node on, `tail` was taken from the `next` link of the
+            // last node, which is owned by the `Box` in the link before it. The map may move
+            // the group, and `head` with it, but not that node, and the list is not touched
+            // anywhere else while it is being filled. The first two links are reached through
+            // `head` instead, since they live in `head` or in the node it owns.
+            _ => unsafe { &mut *self.tail },
+        };
+        self.tail = &mut link.insert(node).next;
+        self.list.len += 1;
+    }
+}
+
+pub struct Iter<'a, T: ?Sized> {
+    ptr: Option<&'a Node<T>>,
+    len: usize,
+}
+
+pub struct IterMut<'a, T> {
+    ptr: Option<&'a mut Box<Node<T>>>,
+    len: usize,
+}
+
+pub struct IntoIter<T> {
+    ptr: LinkedList<T>,
+}
+
+pub struct EnumerateFrom<'a, T> {
+    iter: Iter<'a, T>,
+    index: usize,
+}
+
+pub struct IterChunks<'a, T> {
+    iter: Iter<'a, T>,
+    size: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    First,
+    Middle,
+    Last,
+    Only,
+}
+
+pub struct WithPosition<'a, T> {
+    iter: Iter<'a, T>,
+    first: bool,
+}
+
+impl<'a, T: ?Sized> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        if let Some(node) = self.ptr {
+            self.ptr = node.next.as_deref();
+            self.len -= 1;
+            return Some(&node.data);
+        }
+        None
+    }
+
+    /// Still O(n), as the list has no tail pointer, but skips the per-element bookkeeping.
+    fn last(self) -> Option<Self::Item> {
+        let mut node = self.ptr.filter(|_| self.len > 0)?;
+        for _ in 1..self.len {
+            node = node.next.as_deref()?;
+        }
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T: ?Sized> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            ptr: self.ptr,
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterChunks<'a, T> {
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.iter.len.min(self.size);
+        if len == 0 {
+            return None;
+        }
+        let chunk = Iter {
+            ptr: self.iter.ptr,
+            len,
+        };
+        self.iter.nth(len - 1);
+        Some(chunk)
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.ptr.take()?;
+        self.len -= 1;
+        if self.len > 0 {
+            self.ptr = node.next.as_mut();
+        }
+        Some(&mut node.data)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ptr.pop_front()
+    }
+
+    fn count(self) -> usize {
+        self.ptr.len
+    }
+
+    /// Still O(n), as the list has no tail pointer, but only the last element is moved out.
+    fn last(mut self) -> Option<Self::Item> {
+        self.ptr.pop_last()
+    }
+}
+
+impl<'a, T> Iterator for EnumerateFrom<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.iter.next()?;
+        self.index += 1;
+        Some((self.index - 1, data))
+    }
+}
+
+impl<'a, T> Iterator for WithPosition<'a, T> {
+    type Item = (Position, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.iter.next()?;
+        let first = core::mem::replace(&mut self.first, false);
+        let position = match (first, self.iter.len == 0) {
+            (true, true) => Position::Only,
+            (true, false) => Position::First,
+            (false, true) => Position::Last,
+            (false, false) => Position::Middle,
+        };
+        Some((position, data))
+    }
+}
+
+impl<T: ?Sized> Index<usize> for LinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let mut ptr = &self.head;
+        for _ in 0..index {
+            if let Some(node) = ptr {
+                ptr = &node.next;
+            } else {
+                break;
+            }
+        }
+        ptr.as_deref().unwrap().as_ref()
+    }
+}
+
+impl<T: ?Sized> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let mut ptr = &mut self.head;
+        for _ in 0..index {
+            if let Some(node) = ptr {
+                ptr = &mut node.next;
+            } else {
+                break;
+            }
+        }
+        ptr.as_deref_mut().unwrap().as_mut()
+    }
+}
+
+impl<T: Display + ?Sized> Display for LinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.head.is_none() {
+            write!(f, "None")?;
+        } else {
+            let mut ptr = &self.head;
             while let Some(node) = ptr {
                 write!(
                     f,
                     "{} {}",
-                    node.data,
+                    &node.data,
                     match node.next.is_some() {
                         true => "-> ",
                         false => "",
@@ -341,61 +2073,2026 @@ impl<T: Display> Display for LinkedList<T> {
                 ptr = &node.next;
             }
         }
-        Ok(())
+        Ok(())
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(value: Vec<T>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(value: [T; N]) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl From<&str> for LinkedList<char> {
+    fn from(value: &str) -> Self {
+        value.chars().collect()
+    }
+}
+
+impl From<String> for LinkedList<char> {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut tail = &mut self.head;
+        while let Some(node) = tail {
+            tail = &mut node.next;
+        }
+        for data in iter {
+            tail = &mut tail.insert(Node::new(data)).next;
+            self.len += 1;
+        }
+        self.debug_assert_len_consistent();
+    }
+}
+
+impl<T> From<LinkedList<T>> for Vec<T> {
+    fn from(value: LinkedList<T>) -> Self {
+        let mut list = Vec::with_capacity(value.len);
+        for data in value.into_iter() {
+            list.push(data);
+        }
+        list
+    }
+}
+
+impl From<LinkedList<char>> for String {
+    fn from(value: LinkedList<char>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for LinkedList<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for LinkedList<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.len == N && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for LinkedList<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<LinkedList<T>> for Vec<T> {
+    fn eq(&self, other: &LinkedList<T>) -> bool {
+        other == self
+    }
+}
+
+/// Multiset intersection: each element is kept as many times as it appears in both lists.
+/// Both sides are sorted first, so the result is sorted.
+impl<T: Ord> BitAnd for LinkedList<T> {
+    type Output = LinkedList<T>;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        self.sorted()
+            .merge_join_by(other.sorted(), T::cmp)
+            .into_iter()
+            .filter_map(|joined| match joined {
+                Joined::Both(data, _) => Some(data),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Multiset union: each element is kept as many times as it appears in the list holding more
+/// of it. Both sides are sorted first, so the result is sorted.
+impl<T: Ord> BitOr for LinkedList<T> {
+    type Output = LinkedList<T>;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        self.sorted()
+            .merge_join_by(other.sorted(), T::cmp)
+            .into_iter()
+            .map(|joined| match joined {
+                Joined::Left(data) | Joined::Right(data) | Joined::Both(data, _) => data,
+            })
+            .collect()
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T: ?Sized> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        self.drop_nodes();
+    }
+}
+
+/// The destructor only drops the elements, so with the `nightly` feature the list may outlive
+/// borrows it holds. An element type whose own destructor uses such a borrow is still rejected:
+///
+/// ```compile_fail,E0597
+/// use core::cell::Cell;
+/// use safety_linkedlist::LinkedList;
+///
+/// struct Observer<'a>(&'a Cell<i32>);
+///
+/// impl Drop for Observer<'_> {
+///     fn drop(&mut self) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let mut list = LinkedList::new();
+/// let cell = Cell::new(0);
+/// list.append(Observer(&cell));
+/// ```
+#[cfg(feature = "nightly")]
+// SAFETY: `drop` never accesses a `T` other than by dropping it.
+unsafe impl<#[may_dangle] T: ?Sized> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        self.drop_nodes();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedList;
+    use crate::testing::Rng;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+
+    extern crate std;
+
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct CountingAlloc;
+
+    std::thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    // SAFETY: every call is forwarded to the system allocator unchanged.
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+
+    /// Counts the allocations `f` makes on the current thread.
+    fn allocations(f: impl FnOnce()) -> usize {
+        let before = ALLOCATIONS.with(Cell::get);
+        f();
+        ALLOCATIONS.with(Cell::get) - before
+    }
+
+    struct Counted<'a> {
+        value: i32,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    fn counted(
+        values: impl IntoIterator<Item = i32>,
+        drops: &Cell<usize>,
+    ) -> LinkedList<Counted<'_>> {
+        let values: Vec<Counted<'_>> = values
+            .into_iter()
+            .map(|value| Counted { value, drops })
+            .collect();
+        LinkedList::from(values)
+    }
+
+    struct Bomb<'a> {
+        armed: bool,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Drop for Bomb<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+            if self.armed {
+                panic!("element destructor panicked");
+            }
+        }
+    }
+
+    fn bombs(len: usize, armed: usize, drops: &Cell<usize>) -> LinkedList<Bomb<'_>> {
+        (0..len)
+            .map(|i| Bomb {
+                armed: i == armed,
+                drops,
+            })
+            .collect()
+    }
+
+    fn assert_consistent<T>(list: &LinkedList<T>) {
+        assert_eq!(list.iter().count(), list.len());
+    }
+
+    #[test]
+    fn test() {
+        let mut list = LinkedList::from(vec![1, 2, 3]);
+
+        list.append(1)
+            .append(2)
+            .append(3)
+            .append(4)
+            .prepend(0)
+            .reverse();
+
+        assert_eq!(list.pop_last(), Some(0));
+        assert_eq!(list.pop_front(), Some(4));
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 3));
+        list.remove(2);
+
+        let list: Vec<i32> = list.into();
+        assert_eq!(vec![3, 2, 3, 2, 1], list);
+    }
+
+    #[test]
+    fn test_macro() {
+        let list: LinkedList<i32> = linked_list![];
+        assert!(list.is_empty());
+
+        let list = linked_list![7];
+        assert_eq!(list.len(), 1);
+        assert_eq!(Vec::from(list), vec![7]);
+
+        let list = linked_list![1, 2, 3, 4, 5];
+        assert_eq!(list.len(), 5);
+        assert_eq!(Vec::from(list), vec![1, 2, 3, 4, 5]);
+
+        let list = linked_list!["a", "b",];
+        assert_eq!(Vec::from(list), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_partition_in_place() {
+        let mut list = linked_list![1, 2, 3];
+        assert_eq!(list.partition_in_place(|_| true), 3);
+        assert_list_eq!(list, [1, 2, 3]);
+
+        let mut list = linked_list![1, 2, 3];
+        assert_eq!(list.partition_in_place(|_| false), 0);
+        assert_list_eq!(list, [1, 2, 3]);
+
+        let mut list = linked_list![1, 2, 3, 4, 5, 6];
+        assert_eq!(list.partition_in_place(|x| x % 2 == 0), 3);
+        assert_eq!(list.len(), 6);
+        assert_list_eq!(list, [2, 4, 6, 1, 3, 5]);
+
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.partition_in_place(|_| true), 0);
+        assert_list_eq!(list, []);
+
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for len in 0..50 {
+            let data = rng.vec(len, 10);
+            let mut list = LinkedList::from(data.clone());
+            let split = list.partition_in_place(|x| x % 3 == 0);
+            let (mut expected, rest): (Vec<_>, Vec<_>) = data.into_iter().partition(|x| x % 3 == 0);
+            assert_eq!(split, expected.len());
+            expected.extend(rest);
+            assert_eq!(Vec::from(list), expected);
+        }
+    }
+
+    #[test]
+    fn test_eq() {
+        assert!(linked_list![1, 2, 3] == linked_list![1, 2, 3]);
+        assert!(linked_list![1, 2] != linked_list![1, 2, 3]);
+        assert!(linked_list![1, 2, 3] != linked_list![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_eq_vec() {
+        let list = linked_list![1, 2, 3];
+        assert_eq!(vec![1, 2, 3], list);
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_ne!(vec![1, 2], list);
+        assert_ne!(list, vec![1, 2, 4]);
+        assert_eq!(Vec::<i32>::new(), LinkedList::new());
+    }
+
+    #[test]
+    fn test_split_into() {
+        let parts = LinkedList::from((0..10).collect::<Vec<_>>()).split_into(3);
+        assert_eq!(parts.len(), 3);
+        assert_list_eq!(parts[0], [0, 1, 2, 3]);
+        assert_list_eq!(parts[1], [4, 5, 6]);
+        assert_list_eq!(parts[2], [7, 8, 9]);
+
+        let parts = linked_list![1, 2].split_into(4);
+        assert_eq!(parts.len(), 4);
+        assert_list_eq!(parts[0], [1]);
+        assert_list_eq!(parts[1], [2]);
+        assert_list_eq!(parts[2], []);
+        assert_list_eq!(parts[3], []);
+
+        let parts = linked_list![1, 2, 3, 4, 5, 6].split_into(2);
+        assert_list_eq!(parts[0], [1, 2, 3]);
+        assert_list_eq!(parts[1], [4, 5, 6]);
+
+        let parts = LinkedList::<i32>::new().split_into(2);
+        assert!(parts.iter().all(|part| part.is_empty()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_into_zero() {
+        linked_list![1].split_into(0);
+    }
+
+    #[test]
+    fn test_eq_array() {
+        let list = linked_list![1, 2, 3];
+        assert!(list == [1, 2, 3]);
+        assert!(list != [1, 2]);
+        assert!(list != [1, 2, 3, 4]);
+        assert!(list != [1, 2, 4]);
+        let empty: [i32; 0] = [];
+        assert!(LinkedList::new() == empty);
+    }
+
+    #[test]
+    fn test_merge_all() {
+        #[derive(Debug)]
+        struct Keyed(u64, usize);
+
+        impl PartialEq for Keyed {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Keyed {}
+
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let list = LinkedList::<i32>::merge_all(Vec::new());
+        assert_list_eq!(list, []);
+
+        let list = LinkedList::merge_all([linked_list![1, 2, 3]]);
+        assert_list_eq!(list, [1, 2, 3]);
+
+        let list = LinkedList::merge_all([
+            linked_list![1, 4, 7],
+            LinkedList::new(),
+            linked_list![2, 2, 8],
+        ]);
+        assert_eq!(list.len(), 6);
+        assert_list_eq!(list, [1, 2, 2, 4, 7, 8]);
+
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        let inputs: Vec<Vec<u64>> = (0..8)
+            .map(|i| {
+                let mut data = rng.vec(if i % 3 == 0 { 0 } else { 20 }, 4);
+                data.sort();
+                data
+            })
+            .collect();
+        let lists = inputs.iter().enumerate().map(|(i, data)| {
+            LinkedList::from(data.iter().map(|&x| Keyed(x, i)).collect::<Vec<_>>())
+        });
+        let merged = LinkedList::merge_all(lists);
+        let mut expected: Vec<(u64, usize)> = inputs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, data)| data.iter().map(move |&x| (x, i)))
+            .collect();
+        expected.sort_by_key(|&(x, _)| x);
+        assert_eq!(merged.len(), expected.len());
+        let merged: Vec<(u64, usize)> = merged.iter().map(|k| (k.0, k.1)).collect();
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn test_eq_slice() {
+        let list = linked_list![1, 2, 3];
+        let vec: Vec<i32> = list.iter().copied().collect();
+        assert!(list == vec.as_slice());
+        assert!(list != &vec[..2]);
+        assert!(list != &[1, 2, 4][..]);
+        assert!(list != &[1, 2, 3, 4][..]);
+        assert!(LinkedList::<i32>::new() == &[][..]);
+    }
+
+    #[test]
+    fn test_insert_before() {
+        let mut list = linked_list![1, 2, 3, 2];
+        assert!(list.insert_before(0, |&x| x == 2));
+        assert_list_eq!(list, [1, 0, 2, 3, 2]);
+        assert!(list.insert_before(9, |&x| x == 1));
+        assert_list_eq!(list, [9, 1, 0, 2, 3, 2]);
+
+        let mut list = linked_list![1, 2, 3];
+        assert!(list.insert_before(0, |&x| x == 3));
+        assert_list_eq!(list, [1, 2, 0, 3]);
+
+        let mut list = linked_list![1, 2, 3];
+        assert!(!list.insert_before(4, |&x| x > 3));
+        assert_list_eq!(list, [1, 2, 3, 4]);
+
+        let mut list = LinkedList::new();
+        assert!(!list.insert_before(1, |_| true));
+        assert_list_eq!(list, [1]);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_may_dangle() {
+        let mut list = LinkedList::new();
+        let mut a = 1;
+        let mut b = 2;
+        list.append(&mut a).append(&mut b);
+        for x in list.iter_mut() {
+            **x += 1;
+        }
+        assert_eq!(*list[1], 3);
+    }
+
+    #[test]
+    fn test_insert_after() {
+        let mut list = linked_list![1, 2, 3];
+        assert!(list.insert_after(0, |&x| x == 1));
+        assert_list_eq!(list, [1, 0, 2, 3]);
+
+        let mut list = linked_list![2, 1, 2, 3];
+        assert!(list.insert_after(0, |&x| x == 2));
+        assert_list_eq!(list, [2, 1, 2, 0, 3]);
+
+        let mut list = linked_list![1, 2, 3];
+        assert!(list.insert_after(4, |&x| x == 3));
+        assert_list_eq!(list, [1, 2, 3, 4]);
+
+        let mut list = linked_list![1, 2, 3];
+        assert!(!list.insert_after(0, |&x| x > 3));
+        assert_list_eq!(list, [0, 1, 2, 3]);
+
+        let mut list = LinkedList::new();
+        assert!(!list.insert_after(1, |_| true));
+        assert_list_eq!(list, [1]);
+    }
+
+    #[test]
+    fn test_panic_safety() {
+        let drops = Cell::new(0);
+        let mut list = counted(0..10, &drops);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.partition_in_place(|x| {
+                assert!(x.value != 6);
+                x.value % 2 == 0
+            })
+        }));
+        assert!(result.is_err());
+        assert_consistent(&list);
+        assert_eq!(list.len() + drops.get(), 10);
+        drop(list);
+        assert_eq!(drops.get(), 10);
+
+        let drops = Cell::new(0);
+        let mut list = counted(0..10, &drops);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.insert_before(
+                Counted {
+                    value: 10,
+                    drops: &drops,
+                },
+                |x| {
+                    assert!(x.value != 3);
+                    false
+                },
+            )
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 1);
+        assert_consistent(&list);
+        assert_eq!(list.len(), 10);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.insert_after(
+                Counted {
+                    value: 10,
+                    drops: &drops,
+                },
+                |x| {
+                    assert!(x.value != 3);
+                    false
+                },
+            )
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 10);
+        drop(list);
+        assert_eq!(drops.get(), 12);
+
+        let drops = Cell::new(0);
+        let mut list = counted([1, 4, 5], &drops);
+        let mut other = counted([2, 3, 6], &drops);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.merge_by(&mut other, &mut |a, b| {
+                assert!(a.value != 5 && b.value != 5);
+                a.value.cmp(&b.value)
+            });
+        }));
+        assert!(result.is_err());
+        assert_consistent(&list);
+        assert_eq!(list.len(), 6);
+        assert!(other.is_empty());
+        drop(list);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn test_remove_first() {
+        let mut list = linked_list![1, 2, 3];
+        assert_eq!(list.remove_first(|&x| x == 2), Some(2));
+        assert_list_eq!(list, [1, 3]);
+
+        let mut list = linked_list![1, 2, 3, 4];
+        assert_eq!(list.remove_first(|&x| x % 2 == 0), Some(2));
+        assert_list_eq!(list, [1, 3, 4]);
+        assert_eq!(list.remove_first(|&x| x == 1), Some(1));
+        assert_list_eq!(list, [3, 4]);
+
+        assert_eq!(list.remove_first(|&x| x > 4), None);
+        assert_list_eq!(list, [3, 4]);
+
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(list.remove_first(|_| true), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_remove_last() {
+        let mut list = linked_list![1, 2, 3, 4, 5];
+        assert_eq!(list.remove_last(|&x| x % 2 == 0), Some(4));
+        assert_list_eq!(list, [1, 2, 3, 5]);
+        assert_eq!(list.remove_last(|&x| x % 2 == 1), Some(5));
+        assert_list_eq!(list, [1, 2, 3]);
+
+        assert_eq!(list.remove_last(|&x| x > 3), None);
+        assert_list_eq!(list, [1, 2, 3]);
+
+        let mut list = linked_list![1];
+        assert_eq!(list.remove_last(|&x| x == 1), Some(1));
+        assert_list_eq!(list, []);
+        assert_eq!(list.remove_last(|_| true), None);
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let mut list = linked_list![1, 2, 3, 4, 5, 6];
+        assert_eq!(list.remove_all(|&x| x % 2 == 0), 3);
+        assert_list_eq!(list, [1, 3, 5]);
+        assert_eq!(list.remove_all(|&x| x > 5), 0);
+        assert_list_eq!(list, [1, 3, 5]);
+        assert_eq!(list.remove_all(|_| true), 3);
+        assert_list_eq!(list, []);
+        assert_eq!(list.remove_all(|_| true), 0);
+
+        let drops = Cell::new(0);
+        let mut list = counted(0..10, &drops);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.remove_all(|x| {
+                assert!(x.value != 5);
+                x.value % 2 == 0
+            })
+        }));
+        assert!(result.is_err());
+        assert_consistent(&list);
+        assert_eq!(list.len(), 7);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn test_take_first() {
+        let mut list = linked_list![1, 2, 3, 4];
+        assert_eq!(list.take_first(|&x| x % 2 == 0), Some(2));
+        assert_eq!(list.pop_where(|&x| x % 2 == 0), Some(4));
+        assert_eq!(list.take_first(|&x| x > 4), None);
+        assert_list_eq!(list, [1, 3]);
+    }
+
+    #[test]
+    fn test_len_predicates() {
+        let n = 3;
+        for len in [0, 1, n, n + 1] {
+            let list = LinkedList::from((0..len).collect::<Vec<_>>());
+            assert!(list.len_is(len));
+            assert!(!list.len_is(len + 1));
+            assert_eq!(list.len_is(n), len == n);
+            assert_eq!(list.len_at_least(n), len >= n);
+            assert_eq!(list.len_at_most(n), len <= n);
+            assert!(list.len_at_least(0));
+            assert!(list.len_at_most(len));
+        }
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let mut rng = Rng(0x1234_5678_9abc_def1);
+        for len in 0..20 {
+            let data = rng.vec(len, 100);
+            let mut expected = LinkedList::new();
+            for &x in data.iter() {
+                expected.append(x);
+            }
+            let list = LinkedList::from(data.clone());
+            assert_eq!(list.len(), expected.len());
+            assert_list_eq!(list, expected);
+            let list: LinkedList<u64> = data.iter().copied().collect();
+            assert_list_eq!(list, expected);
+        }
+
+        let mut list = linked_list![1, 2];
+        list.extend([3, 4]);
+        list.extend(Vec::new());
+        assert_eq!(list.len(), 4);
+        assert_list_eq!(list, [1, 2, 3, 4]);
+
+        let start = std::time::Instant::now();
+        let list = LinkedList::from((0..1_000_000).collect::<Vec<u32>>());
+        assert_eq!(list.len(), 1_000_000);
+        assert_eq!(list.last(), Some(&999_999));
+        drop(list);
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_move_to_front() {
+        let mut list = linked_list![1, 2, 3, 4];
+        list.move_to_front(2);
+        assert_list_eq!(list, [3, 1, 2, 4]);
+        list.move_to_front(3);
+        assert_list_eq!(list, [4, 3, 1, 2]);
+        list.move_to_front(0);
+        assert_list_eq!(list, [4, 3, 1, 2]);
+        list.move_to_front(4).move_to_front(10);
+        assert_eq!(list.len(), 4);
+        assert_list_eq!(list, [4, 3, 1, 2]);
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let list = linked_list![1, 2, 3, 3, 5, 6, 7];
+        let i = list.partition_point(|&x| x < 5);
+        assert_eq!(i, 4);
+        assert!(list.iter().take(i).all(|&x| x < 5));
+        assert!(list.iter().skip(i).all(|&x| x >= 5));
+
+        let list = linked_list![1, 2, 3];
+        assert_eq!(list.partition_point(|_| true), 3);
+        assert_eq!(list.partition_point(|_| false), 0);
+        assert_eq!(LinkedList::<i32>::new().partition_point(|_| true), 0);
+
+        let mut list = linked_list![1, 2, 4, 5];
+        let num = 3;
+        let idx = list.partition_point(|&x| x <= num);
+        list.insert(num, idx);
+        assert_list_eq!(list, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_move_to_back() {
+        let mut list = linked_list![1, 2, 3, 4];
+        list.move_to_back(1);
+        assert_list_eq!(list, [1, 3, 4, 2]);
+        list.move_to_back(0);
+        assert_list_eq!(list, [3, 4, 2, 1]);
+        list.move_to_back(3).move_to_back(10);
+        assert_eq!(list.len(), 4);
+        assert_list_eq!(list, [3, 4, 2, 1]);
+    }
+
+    #[test]
+    fn test_trim_matches() {
+        let is_pad = |x: &i32| *x == 0;
+
+        let mut list = linked_list![0, 0, 1, 2];
+        list.trim_start_matches(is_pad);
+        assert_list_eq!(list, [1, 2]);
+        list.trim_end_matches(is_pad);
+        assert_list_eq!(list, [1, 2]);
+
+        let mut list = linked_list![1, 0, 2, 0, 0];
+        list.trim_end_matches(is_pad);
+        assert_list_eq!(list, [1, 0, 2]);
+        list.trim_start_matches(is_pad);
+        assert_list_eq!(list, [1, 0, 2]);
+
+        let mut list = linked_list![0, 1, 0, 2, 0];
+        list.trim_matches(is_pad);
+        assert_eq!(list.len(), 3);
+        assert_list_eq!(list, [1, 0, 2]);
+
+        let mut list = linked_list![1, 2];
+        list.trim_matches(is_pad);
+        assert_list_eq!(list, [1, 2]);
+
+        let drops = Cell::new(0);
+        let mut list = counted([0, 0, 0, 0], &drops);
+        list.trim_end_matches(|x| x.value == 0);
+        assert!(list.is_empty());
+        assert_eq!(drops.get(), 4);
+        let mut list = linked_list![0, 0, 0];
+        list.trim_start_matches(is_pad);
+        assert_list_eq!(list, []);
+        let mut list = linked_list![0, 0, 0];
+        list.trim_matches(is_pad);
+        assert_list_eq!(list, []);
+    }
+
+    #[test]
+    fn test_bubble_sort() {
+        let mut list = linked_list![5, 1, 4, 2, 8, 0, 2];
+        list.bubble_sort();
+        assert_list_eq!(list, [0, 1, 2, 2, 4, 5, 8]);
+
+        let mut list = linked_list![1, 2, 3];
+        list.bubble_sort();
+        assert_list_eq!(list, [1, 2, 3]);
+
+        let mut list = linked_list![3, 2, 1];
+        list.bubble_sort();
+        assert_list_eq!(list, [1, 2, 3]);
+
+        let mut list = LinkedList::<i32>::new();
+        list.bubble_sort();
+        assert_list_eq!(list, []);
+
+        let mut rng = Rng(0x0bad_5eed_dead_beef);
+        for len in 0..30 {
+            let mut data = rng.vec(len, 10);
+            let mut list = LinkedList::from(data.clone());
+            list.bubble_sort();
+            data.sort();
+            assert_list_eq!(list, data);
+        }
+    }
+
+    #[test]
+    fn test_keep_every_nth() {
+        for len in [0, 1, 5, 6, 7, 9] {
+            for n in [1, 2, 3] {
+                let drops = Cell::new(0);
+                let mut list = counted(0..len, &drops);
+                list.keep_every_nth(n, 0);
+                let expected: Vec<i32> = (0..len).step_by(n).collect();
+                assert_eq!(list.len(), (len as usize).div_ceil(n));
+                assert_eq!(drops.get(), len as usize - list.len());
+                assert_list_eq!(list.iter().map(|x| x.value).collect::<Vec<_>>(), expected);
+            }
+        }
+
+        let mut list = linked_list![0, 1, 2, 3, 4, 5, 6];
+        list.keep_every_nth(3, 1);
+        assert_list_eq!(list, [1, 4]);
+
+        let mut list = linked_list![0, 1, 2, 3, 4, 5, 6];
+        list.remove_every_nth(2, 0);
+        assert_list_eq!(list, [1, 3, 5]);
+
+        let mut list = linked_list![0, 1, 2, 3, 4, 5, 6];
+        list.remove_every_nth(3, 2);
+        assert_eq!(list.len(), 5);
+        assert_list_eq!(list, [0, 1, 3, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_keep_every_zeroth() {
+        linked_list![1, 2].keep_every_nth(0, 0);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list = linked_list![1, 2, 3, 4];
+        list.remove(1);
+        assert_eq!(list.len(), 3);
+        assert_list_eq!(list, [1, 3, 4]);
+        list.remove(10);
+        assert_eq!(list.len(), 2);
+        assert_list_eq!(list, [1, 3]);
+        list.remove(0);
+        assert_list_eq!(list, [3]);
+        list.remove(1);
+        assert_list_eq!(list, []);
+        list.remove(0);
+        assert_list_eq!(list, []);
+    }
+
+    #[test]
+    fn test_len_consistent() {
+        let mut list = linked_list![1, 2, 3];
+        list.debug_assert_len_consistent();
+        list.clear();
+        list.debug_assert_len_consistent();
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "stored length 4 differs from the 3 nodes in the list")]
+    fn test_len_inconsistent() {
+        let mut list = linked_list![1, 2, 3];
+        list.len = 4;
+        list.debug_assert_len_consistent();
+    }
+
+    #[test]
+    fn test_counts_sorted() {
+        let list = linked_list!["b", "a", "c", "a", "b", "a"];
+        let counts = list.counts_sorted();
+        assert_list_eq!(counts, [("a", 3), ("b", 2), ("c", 1)]);
+        assert_eq!(counts.iter().map(|&(_, n)| n).sum::<usize>(), list.len());
+
+        let list = linked_list![3, 1, 2];
+        assert_list_eq!(list.counts_sorted(), [(1, 1), (2, 1), (3, 1)]);
+
+        let list = LinkedList::<i32>::new();
+        assert!(list.counts_sorted().is_empty());
+    }
+
+    #[test]
+    fn test_window_sum() {
+        let list = linked_list![1, 2, 3, 4, 5];
+        assert_list_eq!(list.window_sum(3), [6, 9, 12]);
+        assert_list_eq!(list.window_sum(1), list);
+        assert_list_eq!(list.window_sum(5), [15]);
+        assert_list_eq!(list.window_sum(6), []);
+        assert_list_eq!(LinkedList::<i32>::new().window_sum(1), []);
+        assert_list_eq!(linked_list![1.5, -0.5, 2.0].window_sum(2), [1.0, 1.5]);
+        let bytes = LinkedList::<u8>::from(vec![200, 50, 10, 200]);
+        assert_list_eq!(bytes.window_sum(2), [250, 60, 210]);
+        assert_list_eq!(
+            linked_list![u64::MAX - 1, 1, 0, 1].window_sum(2),
+            [u64::MAX, 1, 1]
+        );
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let merge = |a: (i32, i32), b: (i32, i32)| {
+            if b.0 <= a.1 {
+                Ok((a.0, a.1.max(b.1)))
+            } else {
+                Err((a, b))
+            }
+        };
+        let mut list = linked_list![(1, 3), (2, 6), (8, 10), (9, 9), (10, 12), (15, 18)];
+        list.coalesce(merge);
+        assert_list_eq!(list, [(1, 6), (8, 12), (15, 18)]);
+        assert_consistent(&list);
+
+        let mut list = linked_list![(1, 5), (2, 3), (4, 9)];
+        list.coalesce(merge);
+        assert_list_eq!(list, [(1, 9)]);
+
+        let mut list = linked_list![(1, 2), (3, 4), (5, 6)];
+        list.coalesce(merge);
+        assert_list_eq!(list, [(1, 2), (3, 4), (5, 6)]);
+        assert_consistent(&list);
+
+        let mut list = LinkedList::<(i32, i32)>::new();
+        list.coalesce(merge);
+        assert_list_eq!(list, []);
+    }
+
+    #[test]
+    fn test_cumsum() {
+        assert_list_eq!(LinkedList::<i32>::new().cumsum(), []);
+        assert_list_eq!(linked_list![7].cumsum(), [7]);
+        assert_list_eq!(linked_list![1, 2, 3, 4].cumsum(), [1, 3, 6, 10]);
+        assert_list_eq!(linked_list![3, -5, 1, -2].cumsum(), [3, -2, -1, -3]);
+    }
+
+    #[test]
+    fn test_cummax_cummin() {
+        let list = linked_list![3, 1, 4, 1, 5];
+        assert_list_eq!(list.cummax(), [3, 3, 4, 4, 5]);
+        assert_list_eq!(list.cummin(), [3, 1, 1, 1, 1]);
+        assert_list_eq!(LinkedList::<i32>::new().cummax(), []);
+        assert_list_eq!(LinkedList::<i32>::new().cummin(), []);
+        assert_list_eq!(linked_list![2].cummax(), [2]);
+        assert_list_eq!(linked_list![2].cummin(), [2]);
+        let increasing = linked_list![1, 2, 3, 4];
+        assert_list_eq!(increasing.cummax(), increasing);
+        assert_list_eq!(increasing.cummin(), [1, 1, 1, 1]);
+        let decreasing = linked_list![4, 3, 2, 1];
+        assert_list_eq!(decreasing.cummax(), [4, 4, 4, 4]);
+        assert_list_eq!(decreasing.cummin(), decreasing);
+        let constant = linked_list![7, 7, 7];
+        assert_list_eq!(constant.cummax(), constant);
+        assert_list_eq!(constant.cummin(), constant);
+    }
+
+    #[test]
+    fn test_difference_list() {
+        assert_list_eq!(linked_list![1, 4, 9, 16].difference_list(), [3, 5, 7]);
+        assert_list_eq!(linked_list![2, 5, 8, 11].difference_list(), [3, 3, 3]);
+        assert_list_eq!(linked_list![4, 4, 4].difference_list(), [0, 0]);
+        assert_list_eq!(linked_list![1, -1, 1, -1].difference_list(), [-2, 2, -2]);
+        assert_list_eq!(linked_list![1].difference_list(), []);
+        assert_list_eq!(LinkedList::<i32>::new().difference_list(), []);
+    }
+
+    #[test]
+    fn test_scan_with_index() {
+        let list = linked_list![10, 20, 30];
+        assert_list_eq!(list.scan_with_index(|i, x| i as i32 * x), [0, 20, 60]);
+        let indices = linked_list!['a', 'b', 'c', 'd'].scan_with_index(|i, _| i);
+        assert_list_eq!(indices, [0, 1, 2, 3]);
+        assert_consistent(&indices);
+        assert_list_eq!(LinkedList::<i32>::new().scan_with_index(|i, _| i), []);
+    }
+
+    #[test]
+    fn test_panicking_element_drop() {
+        for armed in [0, 3, 5] {
+            let drops = Cell::new(0);
+            let list = bombs(6, armed, &drops);
+            assert!(catch_unwind(AssertUnwindSafe(|| drop(list))).is_err());
+            assert_eq!(drops.get(), 6);
+
+            let drops = Cell::new(0);
+            let mut list = bombs(6, armed, &drops);
+            assert!(catch_unwind(AssertUnwindSafe(|| {
+                list.clear();
+            }))
+            .is_err());
+            assert_eq!(drops.get(), 6);
+            assert!(list.is_empty());
+            assert_consistent(&list);
+
+            let drops = Cell::new(0);
+            let mut iter = bombs(7, armed + 1, &drops).into_iter();
+            drop(iter.next());
+            assert_eq!(drops.get(), 1);
+            assert!(catch_unwind(AssertUnwindSafe(|| drop(iter))).is_err());
+            assert_eq!(drops.get(), 7);
+        }
     }
-}
 
-impl<T> From<Vec<T>> for LinkedList<T> {
-    fn from(value: Vec<T>) -> Self {
+    #[test]
+    fn test_take_every() {
+        let list = linked_list![1, 2, 3, 4, 5, 6];
+        assert_list_eq!(list.take_every(2), [1, 3, 5]);
+        assert_list_eq!(list.take_every(1), list);
+        assert_list_eq!(list.take_every(6), [1]);
+        assert_list_eq!(list.take_every(4), [1, 5]);
+        assert_list_eq!(LinkedList::<i32>::new().take_every(3), []);
+        assert!(catch_unwind(|| linked_list![1].take_every(0)).is_err());
+    }
+
+    #[test]
+    fn test_drop_every() {
+        let list = linked_list![1, 2, 3, 4, 5, 6];
+        assert_list_eq!(list.drop_every(2), [2, 4, 6]);
+        assert_list_eq!(list.drop_every(1), []);
+        assert_list_eq!(list.drop_every(3), [2, 3, 5, 6]);
+        assert_list_eq!(list.drop_every(10), [2, 3, 4, 5, 6]);
+        let mut rest: Vec<i32> = list.take_every(4).into();
+        rest.extend(list.drop_every(4));
+        rest.sort();
+        assert_list_eq!(list, rest);
+        assert!(catch_unwind(|| linked_list![1].drop_every(0)).is_err());
+    }
+
+    #[test]
+    fn test_extend_front() {
+        let mut list = linked_list![4, 5];
+        list.extend_front([1, 2, 3]);
+        assert_list_eq!(list, [1, 2, 3, 4, 5]);
+        list.extend_front(core::iter::empty());
+        assert_list_eq!(list, [1, 2, 3, 4, 5]);
+        assert_consistent(&list);
+
         let mut list = LinkedList::new();
-        for data in value.into_iter() {
-            list.append(data);
+        list.extend_front([1, 2]).extend_front([0]);
+        assert_list_eq!(list, [0, 1, 2]);
+        let mut list = LinkedList::<i32>::new();
+        list.extend_front(core::iter::empty());
+        assert_list_eq!(list, []);
+
+        let mut list: LinkedList<i32> = (5000..6000).collect();
+        list.extend_front(0..5000);
+        assert_eq!(list.len(), 6000);
+        assert!(list.iter().copied().eq(0..6000));
+        assert_consistent(&list);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut rng = Rng(0x7f4a_7c15_9e37_79b9);
+        for len in [0, 1, 2, 3, 10, 100, 1000] {
+            let data = rng.vec(len, 20);
+            let mut list = LinkedList::from(data.clone());
+            list.sort();
+            let mut expected = data.clone();
+            expected.sort();
+            assert_list_eq!(list, expected);
+            assert_consistent(&list);
+
+            let mut pairs: LinkedList<(u64, usize)> = data.iter().copied().zip(0..).collect();
+            pairs.sort_by(|a, b| a.0.cmp(&b.0));
+            let mut expected: Vec<(u64, usize)> = data.iter().copied().zip(0..).collect();
+            expected.sort_by_key(|p| p.0);
+            assert_list_eq!(pairs, expected);
         }
-        list
+
+        let drops = Cell::new(0);
+        let mut list = counted([5, 1, 4, 2, 3, 0, 7], &drops);
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                assert!(calls < 6);
+                a.value.cmp(&b.value)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 0);
+        assert_eq!(list.len(), 7);
+        assert_consistent(&list);
+        let mut values: Vec<i32> = list.iter().map(|c| c.value).collect();
+        values.sort();
+        assert_eq!(values, [0, 1, 2, 3, 4, 5, 7]);
     }
-}
 
-impl<T> Into<Vec<T>> for LinkedList<T> {
-    fn into(self) -> Vec<T> {
-        let mut list = Vec::with_capacity(self.len);
-        for data in self.into_iter() {
-            list.push(data);
+    #[test]
+    fn test_owned_combinators() {
+        let list = LinkedList::from(vec![3, 1, 2])
+            .reversed()
+            .sorted()
+            .with_appended(9)
+            .with_prepended(0);
+        assert_list_eq!(list, [0, 1, 2, 3, 9]);
+        assert_consistent(&list);
+        assert_list_eq!(LinkedList::new().with_prepended(1).reversed(), [1]);
+        assert_list_eq!(LinkedList::<i32>::new().sorted(), []);
+    }
+
+    #[test]
+    fn test_push_mut() {
+        let mut list = LinkedList::new();
+        *list.push_back_mut(1) += 10;
+        *list.push_back_mut(2) += 10;
+        *list.push_front_mut(0) -= 10;
+        assert_list_eq!(list, [-10, 11, 12]);
+        *list.insert_mut(5, 1) *= 3;
+        *list.insert_mut(7, 0) *= 2;
+        *list.insert_mut(9, 5) += 1;
+        *list.insert_mut(3, 99) += 1;
+        assert_list_eq!(list, [14, -10, 15, 11, 12, 10, 4]);
+        assert_consistent(&list);
+    }
+
+    #[test]
+    fn test_move_range() {
+        let list: LinkedList<char> = "abcdef".chars().collect();
+        assert_list_eq!(
+            *list.clone().move_range(0..2, 1),
+            ['c', 'a', 'b', 'd', 'e', 'f']
+        );
+        assert_list_eq!(
+            *list.clone().move_range(1..3, 4),
+            ['a', 'd', 'e', 'f', 'b', 'c']
+        );
+        assert_list_eq!(
+            *list.clone().move_range(3..5, 1),
+            ['a', 'd', 'e', 'b', 'c', 'f']
+        );
+        assert_list_eq!(
+            *list.clone().move_range(4.., 0),
+            ['e', 'f', 'a', 'b', 'c', 'd']
+        );
+        assert_list_eq!(
+            *list.clone().move_range(..2, 4),
+            ['c', 'd', 'e', 'f', 'a', 'b']
+        );
+        assert_list_eq!(*list.clone().move_range(2..2, 0), list);
+        assert!(catch_unwind(|| {
+            linked_list![1, 2, 3].move_range(0..2, 2);
+        })
+        .is_err());
+
+        let mut rng = Rng(0x3c6e_f372_fe94_f82b);
+        for _ in 0..200 {
+            let len = (rng.next() % 12) as usize;
+            let start = (rng.next() % (len as u64 + 1)) as usize;
+            let end = start + (rng.next() % ((len - start) as u64 + 1)) as usize;
+            let dest = (rng.next() % ((len - (end - start)) as u64 + 1)) as usize;
+            let mut expected: Vec<usize> = (0..len).collect();
+            let block: Vec<usize> = expected.drain(start..end).collect();
+            expected.splice(dest..dest, block);
+            let mut list: LinkedList<usize> = (0..len).collect();
+            list.move_range(start..end, dest);
+            assert_list_eq!(list, expected);
+            assert_consistent(&list);
         }
-        list
     }
-}
 
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        while self.pop_front().is_some() {}
+    #[test]
+    fn test_into_iter_count() {
+        let drops = Cell::new(0);
+        let mut iter = counted(0..5, &drops).into_iter();
+        drop(iter.next());
+        assert_eq!(iter.count(), 4);
+        assert_eq!(drops.get(), 5);
+        assert_eq!(LinkedList::<i32>::new().into_iter().count(), 0);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::LinkedList;
-    use alloc::vec;
-    use alloc::vec::Vec;
+    #[test]
+    fn test_iter_last() {
+        let list = linked_list![1, 2, 3, 4];
+        assert_eq!(list.iter().last(), Some(&4));
+        assert_eq!(list.view(..2).iter().last(), Some(&2));
+        assert_eq!(list.view(1..1).iter().last(), None);
+        let mut iter = list.iter();
+        iter.next();
+        assert_eq!(iter.last(), Some(&4));
+        assert_eq!(LinkedList::<i32>::new().iter().last(), None);
+
+        let mut iter = list.clone().into_iter();
+        iter.next();
+        assert_eq!(iter.last(), Some(4));
+        assert_eq!(linked_list![1].into_iter().last(), Some(1));
+        assert_eq!(LinkedList::<i32>::new().into_iter().last(), None);
+    }
 
     #[test]
-    fn test() {
-        let mut list = LinkedList::from(vec![1, 2, 3]);
+    fn test_permute() {
+        let mut list = linked_list!['a', 'b', 'c', 'd'];
+        list.permute(&[0, 1, 2, 3]);
+        assert_list_eq!(list, ['a', 'b', 'c', 'd']);
+        list.permute(&[3, 2, 1, 0]);
+        assert_list_eq!(list, ['d', 'c', 'b', 'a']);
+        list.permute(&[1, 3, 0, 2]);
+        assert_list_eq!(list, ['c', 'a', 'd', 'b']);
+        LinkedList::<i32>::new().permute(&[]);
 
-        list.append(1)
-            .append(2)
-            .append(3)
-            .append(4)
-            .prepend(0)
-            .reverse();
+        let mut rng = Rng(0x6a09_e667_f3bc_c908);
+        for len in [1, 2, 7, 50] {
+            let mut perm: Vec<usize> = (0..len).collect();
+            for i in (1..len).rev() {
+                perm.swap(i, rng.next() as usize % (i + 1));
+            }
+            let data = rng.vec(len, 1000);
+            let mut list = LinkedList::from(data.clone());
+            list.permute(&perm);
+            let expected: Vec<u64> = perm.iter().map(|&i| data[i]).collect();
+            assert_list_eq!(list, expected);
+            assert_consistent(&list);
+        }
 
-        assert_eq!(list.pop_last(), Some(0));
-        assert_eq!(list.pop_front(), Some(4));
+        for perm in [&[0, 0, 1][..], &[0, 1, 3], &[0, 1], &[2, 1, 0, 3]] {
+            let mut list = linked_list![1, 2, 3];
+            assert!(catch_unwind(AssertUnwindSafe(|| {
+                list.permute(perm);
+            }))
+            .is_err());
+            assert_list_eq!(list, [1, 2, 3]);
+        }
+    }
 
-        let mut iter = list.iter_mut();
-        assert_eq!(iter.next(), Some(&mut 3));
-        list.remove(2);
+    #[test]
+    fn test_sum_pairs() {
+        assert_list_eq!(linked_list![1, 2, 3, 4, 5, 6].sum_pairs(), [3, 7, 11]);
+        assert_list_eq!(linked_list![1, 2, 3, 4, 5].sum_pairs(), [3, 7]);
+        assert_list_eq!(linked_list![4, 5].sum_pairs(), [9]);
+        assert_list_eq!(linked_list![4].sum_pairs(), []);
+        assert_list_eq!(LinkedList::<i32>::new().sum_pairs(), []);
+    }
 
-        let list: Vec<i32> = list.into();
-        assert_eq!(vec![3, 2, 3, 2, 1], list);
+    #[test]
+    fn test_index_of_ref() {
+        let list = linked_list![7, 7, 3, 7];
+        let refs: Vec<&i32> = list.iter().collect();
+        for (i, &r) in refs.iter().enumerate() {
+            assert_eq!(list.index_of_ref(r), Some(i));
+        }
+        let other = list.clone();
+        assert_eq!(list.index_of_ref(other.first().unwrap()), None);
+        assert_eq!(list.index_of_ref(&7), None);
+        assert_eq!(LinkedList::new().index_of_ref(refs[0]), None);
+    }
+
+    #[test]
+    fn test_repeat_with() {
+        assert_list_eq!(LinkedList::repeat_with(5, || 0), [0, 0, 0, 0, 0]);
+        assert_list_eq!(LinkedList::repeat_with(0, || 1), []);
+        let mut next = 0;
+        let list = LinkedList::repeat_with(4, || {
+            next += 2;
+            next
+        });
+        assert_list_eq!(list, [2, 4, 6, 8]);
+        assert_consistent(&list);
+    }
+
+    #[test]
+    fn test_sort_unstable() {
+        let mut rng = Rng(0xbb67_ae85_84ca_a73b);
+        let shapes: [fn(usize, &mut Rng) -> u64; 5] = [
+            |_, rng| rng.next() % 1000,
+            |_, rng| rng.next() % 3,
+            |i, _| i as u64,
+            |i, _| u64::MAX - i as u64,
+            |i, _| (i as u64).min(5000 - i as u64),
+        ];
+        for len in [0, 1, 2, 3, 5, 16, 100, 5000] {
+            for shape in shapes {
+                let data: Vec<u64> = (0..len).map(|i| shape(i, &mut rng)).collect();
+                let mut list = LinkedList::from(data.clone());
+                list.sort_unstable();
+                let mut expected = data.clone();
+                expected.sort_unstable();
+                assert_list_eq!(list, expected);
+                assert_consistent(&list);
+            }
+        }
+
+        let mut list = LinkedList::from(rng.vec(300, 50));
+        let mut expected: Vec<u64> = list.iter().copied().collect();
+        list.sort_unstable_by(|a, b| b.cmp(a));
+        expected.sort_unstable_by(|a, b| b.cmp(a));
+        assert_list_eq!(list, expected);
+
+        for limit in [0, 1, 3] {
+            let data = rng.vec(300, 50);
+            let mut list = LinkedList::from(data.clone());
+            list.quicksort(&mut u64::cmp, limit);
+            let mut expected = data;
+            expected.sort_unstable();
+            assert_list_eq!(list, expected);
+            assert_consistent(&list);
+        }
+
+        let drops = Cell::new(0);
+        let mut order: Vec<i32> = (0..50).collect();
+        for i in (1..order.len()).rev() {
+            order.swap(i, (rng.next() % (i as u64 + 1)) as usize);
+        }
+        let mut list = counted(order, &drops);
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.sort_unstable_by(|a, b| {
+                calls += 1;
+                assert!(calls < 100);
+                a.value.cmp(&b.value)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 0);
+        assert_consistent(&list);
+        let mut values: Vec<i32> = list.iter().map(|c| c.value).collect();
+        values.sort_unstable();
+        assert!(values.into_iter().eq(0..50));
+    }
+
+    #[test]
+    fn test_enumerate_from() {
+        let list = linked_list!['a', 'b', 'c'];
+        assert!(list.enumerate_from(0).eq(list.iter().enumerate()));
+        let items: Vec<(usize, &char)> = list.enumerate_from(5).collect();
+        assert_eq!(items, [(5, &'a'), (6, &'b'), (7, &'c')]);
+        assert_eq!(LinkedList::<i32>::new().enumerate_from(3).next(), None);
+    }
+
+    #[test]
+    fn test_first_array() {
+        let mut list = linked_list![0x10, 1, 2, 3];
+        let [opcode, a, b] = list.first_array().unwrap();
+        assert_eq!((*opcode, *a, *b), (0x10, 1, 2));
+        assert_eq!(list.first_array::<4>(), Some([&0x10, &1, &2, &3]));
+        assert_eq!(list.first_array::<5>(), None);
+        assert_eq!(list.first_array::<0>(), Some([]));
+
+        if let Some([first, second]) = list.first_array_mut() {
+            core::mem::swap(first, second);
+            *second += 1;
+        }
+        assert_list_eq!(list, [1, 0x11, 2, 3]);
+        assert!(list.first_array_mut::<5>().is_none());
+        assert!(LinkedList::<i32>::new().first_array::<1>().is_none());
+    }
+
+    #[test]
+    fn test_into_sorted_linked_list() {
+        let sorted = linked_list![5, 3, 8, 1, 3].into_sorted_linked_list();
+        assert_list_eq!(sorted, [1, 3, 3, 5, 8]);
+        assert_eq!(sorted.len(), 5);
+        assert_consistent(&sorted);
+        assert_list_eq!(LinkedList::<i32>::new().into_sorted_linked_list(), []);
+    }
+
+    #[test]
+    fn test_copy_within() {
+        use core::ops::Bound;
+
+        let mut list: LinkedList<i32> = (0..8).collect();
+        list.copy_within(0..2, 5);
+        assert_list_eq!(list, [0, 1, 2, 3, 4, 0, 1, 7]);
+        list.copy_within(6.., 0);
+        assert_list_eq!(list, [1, 7, 2, 3, 4, 0, 1, 7]);
+
+        for (src, dest) in [(0..5, 2), (3..8, 1), (2..6, 2), (0..8, 0), (4..4, 8)] {
+            let mut list: LinkedList<i32> = (0..8).collect();
+            let mut expected: Vec<i32> = (0..8).collect();
+            expected.copy_within(src.clone(), dest);
+            list.copy_within(src, dest);
+            assert_list_eq!(list, expected);
+            assert_consistent(&list);
+        }
+
+        let mut list: LinkedList<String> = ["a", "b", "c"].map(String::from).into();
+        list.copy_within(..2, 1);
+        assert_list_eq!(list, ["a", "a", "b"]);
+        assert!(catch_unwind(AssertUnwindSafe(|| list.copy_within(1.., 2))).is_err());
+        assert!(catch_unwind(AssertUnwindSafe(|| list.copy_within(1..4, 0))).is_err());
+        let result = catch_unwind(AssertUnwindSafe(|| list.copy_within(0..=usize::MAX, 0)));
+        assert!(result.is_err());
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.copy_within((Bound::Excluded(usize::MAX), Bound::Unbounded), 0)
+        }));
+        assert!(result.is_err());
+        assert_list_eq!(list, ["a", "a", "b"]);
+    }
+
+    #[test]
+    fn test_find_map() {
+        let list = linked_list![3, 5, 6, 8];
+        assert_eq!(list.find_map(|&x| (x % 2 == 0).then(|| x * x)), Some(36));
+        assert_eq!(list.find_map(|&x| (x > 10).then_some(x)), None);
+        assert_eq!(LinkedList::<i32>::new().find_map(|&x| Some(x)), None);
+        let mut calls = 0;
+        list.find_map(|&x| {
+            calls += 1;
+            (x == 5).then_some(())
+        });
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_for_each_while() {
+        let list = linked_list![1, 2, 3, 4, 5];
+        let mut seen = Vec::new();
+        assert_eq!(
+            list.for_each_while(|&x| {
+                seen.push(x);
+                x < 3
+            }),
+            2
+        );
+        assert_eq!(seen, [1, 2, 3]);
+        assert_eq!(list.for_each_while(|_| true), 5);
+        assert_eq!(list.for_each_while(|_| false), 0);
+        assert_eq!(LinkedList::<i32>::new().for_each_while(|_| false), 0);
+    }
+
+    #[test]
+    fn test_group_to_btree_map() {
+        let events = linked_list![("net", 1), ("disk", 2), ("net", 3), ("cpu", 4), ("net", 5)];
+        let groups = events.group_to_btree_map(|e| e.0);
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<_>>(),
+            ["cpu", "disk", "net"]
+        );
+        assert_list_eq!(groups["net"], [("net", 1), ("net", 3), ("net", 5)]);
+        assert_list_eq!(groups["disk"], [("disk", 2)]);
+        assert_eq!(groups.values().map(LinkedList::len).sum::<usize>(), 5);
+        groups.values().for_each(assert_consistent);
+
+        let groups = linked_list![3, 1, 2].group_to_btree_map(|_| "all");
+        assert_list_eq!(groups["all"], [3, 1, 2]);
+
+        // Enough keys that the map moves its groups while they are being filled.
+        let list: LinkedList<u32> = (0..300).map(|x| x * 7 % 300).collect();
+        let groups = list.clone().group_to_btree_map(|x| x % 50);
+        assert_eq!(groups.len(), 50);
+        for (key, group) in &groups {
+            let expected: Vec<u32> = list.iter().copied().filter(|x| x % 50 == *key).collect();
+            assert_list_eq!(*group, expected);
+            assert_consistent(group);
+        }
+        assert!(LinkedList::<i32>::new()
+            .group_to_btree_map(|&x| x)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_contains_sequence() {
+        let list = linked_list![1, 2, 3, 4];
+        assert!(list.contains_sequence(&[2, 3]));
+        assert!(!list.contains_sequence(&[3, 2]));
+        assert!(list.contains_sequence(&[]));
+        assert!(list.contains_sequence(&[1, 2, 3, 4]));
+        assert!(list.contains_sequence(&[4]));
+        assert!(!list.contains_sequence(&[1, 2, 3, 4, 5]));
+        assert!(linked_list![1, 1, 2].contains_sequence(&[1, 2]));
+        assert!(LinkedList::<i32>::new().contains_sequence(&[]));
+    }
+
+    #[test]
+    fn test_iter_with_position() {
+        use super::Position::{First, Last, Middle, Only};
+
+        let positions = |list: LinkedList<i32>| -> Vec<_> {
+            list.iter_with_position().map(|(p, _)| p).collect()
+        };
+        assert_eq!(positions(LinkedList::new()), []);
+        assert_eq!(positions(linked_list![1]), [Only]);
+        assert_eq!(positions(linked_list![1, 2]), [First, Last]);
+        assert_eq!(
+            positions(linked_list![1, 2, 3, 4, 5]),
+            [First, Middle, Middle, Middle, Last]
+        );
+
+        let list = linked_list![1, 2, 3];
+        let mut out = String::new();
+        for (position, x) in list.iter_with_position() {
+            out.push_str(&format!("{}", x));
+            if !matches!(position, Last | Only) {
+                out.push_str(", ");
+            }
+        }
+        assert_eq!(out, "1, 2, 3");
+    }
+
+    #[test]
+    fn test_starts_ends_with() {
+        let list = linked_list![1, 2, 3];
+        assert!(list.starts_with(&[1, 2]));
+        assert!(!list.starts_with(&[2, 3]));
+        assert!(!list.starts_with(&[1, 2, 3, 4]));
+        assert!(list.starts_with(&[]));
+        assert!(list.ends_with(&[2, 3]));
+        assert!(list.ends_with(&[1, 2, 3]));
+        assert!(!list.ends_with(&[1, 2]));
+        assert!(!list.ends_with(&[0, 1, 2, 3]));
+        assert!(list.ends_with(&[]));
+        assert!(LinkedList::<i32>::new().starts_with(&[]));
+        assert!(!LinkedList::new().ends_with(&[1]));
+    }
+
+    #[test]
+    fn test_by_key_mut() {
+        let mut list = linked_list![("a", 3), ("b", 7), ("c", 1), ("d", 7), ("e", 1)];
+        let max = list.max_by_key_mut(|e| e.1).unwrap();
+        assert_eq!(max.0, "b");
+        max.1 += 1;
+        let min = list.min_by_key_mut(|e| e.1).unwrap();
+        assert_eq!(min.0, "c");
+        min.1 = 0;
+        assert_list_eq!(list, [("a", 3), ("b", 8), ("c", 0), ("d", 7), ("e", 1)]);
+
+        let mut list = linked_list![5];
+        *list.max_by_key_mut(|&x| x).unwrap() += 1;
+        *list.min_by_key_mut(|&x| x).unwrap() += 1;
+        assert_list_eq!(list, [7]);
+        assert!(LinkedList::<i32>::new().max_by_key_mut(|&x| x).is_none());
+        assert!(LinkedList::<i32>::new().min_by_key_mut(|&x| x).is_none());
+    }
+
+    #[test]
+    fn test_drain_while() {
+        let mut list = linked_list![1, 2, 3, 10, 4];
+        assert_list_eq!(list.drain_while(|&x| x > 5), []);
+        assert_list_eq!(list.drain_while(|&x| x < 5), [1, 2, 3]);
+        assert_list_eq!(list, [10, 4]);
+        assert_consistent(&list);
+        let drained = list.drain_while(|_| true);
+        assert_list_eq!(drained, [10, 4]);
+        assert_consistent(&drained);
+        assert!(list.is_empty());
+        assert_list_eq!(list.drain_while(|_| true), []);
+    }
+
+    #[test]
+    fn test_sort_indices() {
+        let list = linked_list![30, 10, 20, 10];
+        assert_eq!(list.sort_indices(), [1, 3, 2, 0]);
+        assert_eq!(list.sort_indices_by(|a, b| b.cmp(a)), [0, 2, 1, 3]);
+        assert_eq!(list.sort_indices_by_key(|&x| x / 20), [1, 3, 0, 2]);
+        assert_list_eq!(list, [30, 10, 20, 10]);
+        assert!(LinkedList::<i32>::new().sort_indices().is_empty());
+
+        let mut rng = Rng(0x510e_527f_ade6_82d1);
+        let data = rng.vec(200, 10);
+        let list = LinkedList::from(data.clone());
+        let indices = list.sort_indices();
+        let mut expected: Vec<(usize, u64)> = data.iter().copied().enumerate().collect();
+        expected.sort_by_key(|p| p.1);
+        assert!(indices.iter().copied().eq(expected.iter().map(|p| p.0)));
+        let mut copy = list.clone();
+        copy.permute(&indices);
+        copy.debug_assert_len_consistent();
+        assert_list_eq!(copy, list.clone().sorted());
+        assert_list_eq!(list, data);
+    }
+
+    #[test]
+    fn test_all_equal() {
+        assert!(linked_list![7, 7, 7, 7].all_equal());
+        assert!(!linked_list![1, 2, 3].all_equal());
+        assert!(!linked_list![7, 7, 7, 8].all_equal());
+        assert!(linked_list![1].all_equal());
+        assert!(LinkedList::<i32>::new().all_equal());
+    }
+
+    #[test]
+    fn test_is_subset_and_is_disjoint() {
+        let list = linked_list![3, 1, 2, 1];
+        assert!(linked_list![1, 2].is_subset(&list));
+        assert!(linked_list![1, 1, 3].is_subset(&list));
+        assert!(!linked_list![1, 1, 1].is_subset(&list));
+        assert!(!linked_list![4].is_subset(&list));
+        assert!(list.is_subset(&list));
+        assert!(!list.is_subset(&linked_list![1, 2, 3]));
+        assert!(LinkedList::new().is_subset(&list));
+        assert!(LinkedList::<i32>::new().is_subset(&LinkedList::new()));
+        assert!(!list.is_subset(&LinkedList::new()));
+
+        assert!(list.is_disjoint(&linked_list![4, 5, 4]));
+        assert!(!list.is_disjoint(&linked_list![5, 2]));
+        assert!(!list.is_disjoint(&list));
+        assert!(list.is_disjoint(&LinkedList::new()));
+        assert!(LinkedList::new().is_disjoint(&list));
+        assert!(LinkedList::<i32>::new().is_disjoint(&LinkedList::new()));
+    }
+
+    #[test]
+    fn test_all_unique() {
+        assert!(linked_list![3, 1, 4, 5, 9].all_unique());
+        assert!(!linked_list![3, 3, 1, 4].all_unique());
+        assert!(!linked_list![3, 1, 4, 1].all_unique());
+        assert!(linked_list![1].all_unique());
+        assert!(LinkedList::<i32>::new().all_unique());
+    }
+
+    #[test]
+    fn test_split_mut_at() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+        let (mut front, mut back) = list.split_mut_at(2);
+        let a = front.next().unwrap();
+        let b = back.next().unwrap();
+        core::mem::swap(a, b);
+        *front.next().unwrap() += 10;
+        *back.next().unwrap() += 20;
+        assert_eq!(front.next(), None);
+        for x in back {
+            *x *= -1;
+        }
+        assert_list_eq!(list, [2, 11, 0, 23, -4, -5]);
+
+        for at in 0..=4 {
+            let mut list = linked_list![1, 2, 3, 4];
+            let (front, back) = list.split_mut_at(at);
+            front.for_each(|x| *x = 0);
+            back.for_each(|x| *x += 10);
+            let mut expected = alloc::vec![0; at];
+            expected.extend((at as i32 + 1..=4).map(|x| x + 10));
+            assert_list_eq!(list, expected);
+            list.debug_assert_len_consistent();
+        }
+
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let (front, back) = list.split_mut_at(3);
+        let mut refs: Vec<&mut i32> = back.chain(front).collect();
+        for (i, x) in refs.iter_mut().rev().enumerate() {
+            **x += 100 * i as i32;
+        }
+        refs.clear();
+        assert_list_eq!(list, [200, 101, 2, 403, 304]);
+
+        let mut empty = LinkedList::<i32>::new();
+        let (mut front, mut back) = empty.split_mut_at(0);
+        assert_eq!((front.next(), back.next()), (None, None));
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            linked_list![1, 2].split_mut_at(3);
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(linked_list![5, 1, 4, 2, 3].median(), Some(&3));
+        assert_eq!(linked_list![40, 10, 30, 20].median(), Some(&20));
+        assert_eq!(linked_list![7].median(), Some(&7));
+        assert_eq!(LinkedList::<i32>::new().median(), None);
+    }
+
+    #[test]
+    fn test_for_each_pair_mut() {
+        let mut list = linked_list![10, 0, 0, 6];
+        let mut calls = 0;
+        list.for_each_pair_mut(|a, b| {
+            calls += 1;
+            let moved = (*a - *b) / 2;
+            *a -= moved;
+            *b += moved;
+        });
+        assert_eq!(calls, 3);
+        assert_list_eq!(list, [5, 3, 4, 4]);
+
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        list.for_each_pair_mut(core::mem::swap);
+        assert_list_eq!(list, [2, 3, 4, 5, 1]);
+
+        for mut list in [linked_list![1], LinkedList::new()] {
+            list.for_each_pair_mut(|_, _| panic!("no pairs"));
+        }
+    }
+
+    #[test]
+    fn test_nth_smallest() {
+        let list = linked_list![8, 3, 9, 1, 5, 3];
+        assert_eq!(list.nth_smallest(0), Some(&1));
+        assert_eq!(list.nth_smallest(5), Some(&9));
+        assert_eq!(list.nth_smallest(2), Some(&3));
+        assert_eq!(list.nth_smallest(3), Some(&5));
+        assert_eq!(list.nth_smallest(6), None);
+        assert_eq!(LinkedList::<i32>::new().nth_smallest(0), None);
+    }
+
+    #[test]
+    fn test_overwrite_from() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+        assert_eq!(
+            allocations(|| assert_eq!(list.overwrite_from(10..15), 5)),
+            0
+        );
+        assert_list_eq!(list, [10, 11, 12, 13, 14]);
+        assert_eq!(
+            allocations(|| assert_eq!(list.overwrite_from([7, 8]), 2)),
+            0
+        );
+        assert_list_eq!(list, [7, 8]);
+        assert_consistent(&list);
+
+        assert_eq!(allocations(|| assert_eq!(list.overwrite_from(1..=4), 4)), 2);
+        assert_list_eq!(list, [1, 2, 3, 4]);
+        assert_consistent(&list);
+        assert_eq!(list.overwrite_from([]), 0);
+        assert_list_eq!(list, []);
+        assert_eq!(list.overwrite_from([5]), 1);
+        assert_list_eq!(list, [5]);
+
+        let drops = Cell::new(0);
+        let mut list = counted([1, 2, 3], &drops);
+        list.overwrite_from(counted([4], &drops));
+        assert_eq!(drops.get(), 3);
+        assert_eq!(list.first().map(|x| x.value), Some(4));
+        drop(list);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn test_frequency_map() {
+        let list = linked_list![1, 2, 1, 3, 2, 1];
+        let counts = list.frequency_map();
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            [(&1, 3), (&2, 2), (&3, 1)]
+        );
+        assert!(LinkedList::<i32>::new().frequency_map().is_empty());
+    }
+
+    #[test]
+    fn test_transfer() {
+        let mut source: LinkedList<i32> = (0..15).collect();
+        let mut dest = linked_list![-1, -2, -3, -4];
+        source.transfer(5..12, &mut dest, 3);
+        assert_list_eq!(source, [0, 1, 2, 3, 4, 12, 13, 14]);
+        assert_list_eq!(dest, [-1, -2, -3, 5, 6, 7, 8, 9, 10, 11, -4]);
+        assert_consistent(&source);
+        assert_consistent(&dest);
+
+        let mut dest = linked_list![100];
+        source.transfer(..2, &mut dest, 0);
+        assert_list_eq!(dest, [0, 1, 100]);
+        source.transfer(4.., &mut dest, 3);
+        assert_list_eq!(dest, [0, 1, 100, 13, 14]);
+        assert_list_eq!(source, [2, 3, 4, 12]);
+        source.transfer(.., &mut dest, 5);
+        assert_list_eq!(source, []);
+        assert_list_eq!(dest, [0, 1, 100, 13, 14, 2, 3, 4, 12]);
+        assert_consistent(&source);
+        assert_consistent(&dest);
+
+        let mut empty = LinkedList::new();
+        dest.transfer(1..1, &mut empty, 0);
+        assert_list_eq!(empty, []);
+        assert_eq!(dest.len(), 9);
+
+        let drops = Cell::new(0);
+        let mut source = counted([1, 2, 3], &drops);
+        let mut dest = counted([4], &drops);
+        source.transfer(1.., &mut dest, 1);
+        assert_eq!(drops.get(), 0);
+        assert_eq!(dest.iter().map(|x| x.value).collect::<Vec<_>>(), [4, 2, 3]);
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            linked_list![1, 2].transfer(0..3, &mut LinkedList::new(), 0);
+        }));
+        assert!(result.is_err());
+        let mut source = linked_list![1, 2];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            source.transfer(0..1, &mut linked_list![3], 2);
+        }));
+        assert!(result.is_err());
+        assert_list_eq!(source, [1, 2]);
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(linked_list![3, 1, 3, 2, 3, 1].mode(), Some(&3));
+        assert_eq!(linked_list![5, 2, 5, 2, 9].mode(), Some(&2));
+        assert_eq!(linked_list![4, 4, 4].mode(), Some(&4));
+        assert_eq!(linked_list![8].mode(), Some(&8));
+        assert_eq!(LinkedList::<i32>::new().mode(), None);
+    }
+
+    #[test]
+    fn test_equal_range() {
+        let list = linked_list![1, 1, 1, 3, 5, 5, 7, 9, 9];
+        assert_eq!(list.equal_range(&1), 0..3);
+        assert_eq!(list.equal_range(&3), 3..4);
+        assert_eq!(list.equal_range(&5), 4..6);
+        assert_eq!(list.equal_range(&9), 7..9);
+        assert_eq!(list.equal_range(&0), 0..0);
+        assert_eq!(list.equal_range(&4), 4..4);
+        assert_eq!(list.equal_range(&10), 9..9);
+        assert_eq!(LinkedList::new().equal_range(&1), 0..0);
+
+        let list = linked_list![(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')];
+        assert_eq!(list.equal_range_by_key(&2, |p| p.0), 1..3);
+        assert_eq!(list.equal_range_by_key(&3, |p| p.0), 3..3);
+        assert_eq!(list.equal_range_by(|p| p.0.cmp(&4)), 3..4);
+    }
+
+    #[test]
+    fn test_into_string() {
+        let list = linked_list!['h', 'e', 'l', 'l', 'o'];
+        assert_eq!(String::from(list), "hello");
+        let list: LinkedList<char> = "añ字🦀".chars().collect();
+        assert_eq!(String::from(list), "añ字🦀");
+        assert_eq!(String::from(LinkedList::new()), "");
+    }
+
+    #[test]
+    fn test_iter_chunks() {
+        let list: LinkedList<i32> = (0..10).collect();
+        let chunks: Vec<Vec<i32>> = list
+            .iter_chunks(4)
+            .map(|chunk| chunk.copied().collect())
+            .collect();
+        assert_eq!(chunks, [vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]);
+        let lens: Vec<usize> = list.iter_chunks(5).map(|chunk| chunk.len()).collect();
+        assert_eq!(lens, [5, 5]);
+        for size in 1..=11 {
+            assert!(list.iter_chunks(size).flatten().eq(list.iter()));
+            assert_eq!(list.iter_chunks(size).count(), 10usize.div_ceil(size));
+        }
+        assert_eq!(LinkedList::<i32>::new().iter_chunks(3).count(), 0);
+        assert!(catch_unwind(|| list.iter_chunks(0).count()).is_err());
+    }
+
+    #[test]
+    fn test_from_str_slice() {
+        assert_list_eq!(LinkedList::from("hello"), ['h', 'e', 'l', 'l', 'o']);
+        let list = LinkedList::from("añ字🦀");
+        assert_list_eq!(list, ['a', 'ñ', '字', '🦀']);
+        assert_eq!(list.len(), 4);
+        assert_list_eq!(LinkedList::from(""), []);
+    }
+
+    #[test]
+    fn test_sort_floats() {
+        let mut list = linked_list![
+            3.5f64,
+            f64::NAN,
+            -0.0,
+            f64::INFINITY,
+            0.0,
+            -f64::NAN,
+            -1.0,
+            f64::NEG_INFINITY,
+            0.0
+        ];
+        list.sort_floats();
+        let bits: Vec<u64> = list.iter().map(|x| x.to_bits()).collect();
+        let expected = [
+            -f64::NAN,
+            f64::NEG_INFINITY,
+            -1.0,
+            -0.0,
+            0.0,
+            0.0,
+            3.5,
+            f64::INFINITY,
+            f64::NAN,
+        ];
+        assert!(bits.into_iter().eq(expected.iter().map(|x| x.to_bits())));
+        assert_consistent(&list);
+
+        let mut list = linked_list![f32::NAN, 2.0f32, -0.0, 0.0, f32::NEG_INFINITY];
+        list.sort_floats();
+        let bits: Vec<u32> = list.iter().map(|x| x.to_bits()).collect();
+        let expected = [f32::NEG_INFINITY, -0.0, 0.0, 2.0, f32::NAN];
+        assert!(bits.into_iter().eq(expected.iter().map(|x| x.to_bits())));
+    }
+
+    #[test]
+    fn test_from_string() {
+        let text = String::from("añ字🦀");
+        let mut list = LinkedList::new();
+        assert_eq!(allocations(|| list = LinkedList::from(text)), 4);
+        assert_list_eq!(list, ['a', 'ñ', '字', '🦀']);
+        assert_eq!(String::from(list), "añ字🦀");
+        assert_list_eq!(LinkedList::from(String::new()), []);
+    }
+
+    #[test]
+    fn test_from_elem() {
+        struct CloneCounter<'a>(&'a Cell<usize>);
+
+        impl Clone for CloneCounter<'_> {
+            fn clone(&self) -> Self {
+                self.0.set(self.0.get() + 1);
+                CloneCounter(self.0)
+            }
+        }
+
+        let clones = Cell::new(0);
+        let list = LinkedList::from_elem(CloneCounter(&clones), 5);
+        assert_eq!(list.len(), 5);
+        assert_eq!(clones.get(), 4);
+        assert_consistent(&list);
+        let list = LinkedList::from_elem(CloneCounter(&clones), 1);
+        assert_eq!(list.len(), 1);
+        assert_eq!(clones.get(), 4);
+        let list = LinkedList::from_elem(CloneCounter(&clones), 0);
+        assert!(list.is_empty());
+        assert_eq!(clones.get(), 4);
+
+        assert_list_eq!(linked_list![7; 3], [7, 7, 7]);
+        assert_list_eq!(linked_list![String::from("a"); 2], ["a", "a"]);
+        let empty: LinkedList<i32> = linked_list![1; 0];
+        assert_list_eq!(empty, []);
+    }
+
+    #[test]
+    fn test_bytes_into_vec() {
+        let list: LinkedList<u8> = (0..1000).map(|x| (x % 251) as u8).collect();
+        let mut bytes = Vec::new();
+        assert_eq!(allocations(|| bytes = Vec::from(list)), 1);
+        assert_eq!(bytes.len(), 1000);
+        assert_eq!(bytes.capacity(), 1000);
+        assert!(bytes.iter().enumerate().all(|(i, &x)| x == (i % 251) as u8));
+    }
+
+    #[test]
+    fn test_find_subsequence() {
+        let list: LinkedList<char> = "aaab".chars().collect();
+        assert_eq!(list.find_subsequence(&"aab".chars().collect()), Some(1));
+        assert_eq!(list.find_subsequence_slice(&['a', 'a']), Some(0));
+        assert_eq!(list.find_subsequence_slice(&['b']), Some(3));
+        assert_eq!(list.find_subsequence_slice(&['a', 'b']), Some(2));
+        assert_eq!(list.find_subsequence_slice(&['b', 'a']), None);
+        assert_eq!(list.find_subsequence_slice(&['a'; 5]), None);
+        assert_eq!(list.find_subsequence(&LinkedList::new()), Some(0));
+        assert!(list.contains_subsequence(&list.clone()));
+        assert!(!list.contains_subsequence(&linked_list!['c']));
+
+        let bytes = linked_list![0x00u8, 0x7e, 0x7e, 0x10, 0x7e, 0x7e, 0x7e, 0x81];
+        let sync = linked_list![0x7e, 0x7e, 0x81];
+        assert_eq!(bytes.find_subsequence(&sync), Some(5));
+        let empty = LinkedList::<u8>::new();
+        assert_eq!(empty.find_subsequence(&LinkedList::new()), Some(0));
+        assert_eq!(empty.find_subsequence(&sync), None);
+    }
+
+    #[test]
+    fn test_bitand() {
+        assert_list_eq!(linked_list![1, 3, 5] & linked_list![2, 4, 6], []);
+        assert_list_eq!(linked_list![3, 1, 2] & linked_list![3, 1, 2], [1, 2, 3]);
+        let both = linked_list![5, 1, 3, 1, 1, 4] & linked_list![1, 4, 4, 1, 2];
+        assert_list_eq!(both, [1, 1, 4]);
+        assert_consistent(&both);
+        assert_list_eq!(LinkedList::new() & linked_list![1, 2], []);
+        assert_list_eq!(linked_list![1, 2] & LinkedList::new(), []);
+    }
+
+    #[test]
+    fn test_replace_subsequence() {
+        let mut list = linked_list![1, 2, 3, 1, 2, 3];
+        assert!(list.replace_subsequence(&[1, 2], [9]));
+        assert_list_eq!(list, [9, 3, 1, 2, 3]);
+        assert!(list.replace_subsequence(&[2, 3], [7, 7, 7]));
+        assert_list_eq!(list, [9, 3, 1, 7, 7, 7]);
+        assert_consistent(&list);
+        assert!(list.replace_subsequence(&[3, 1], []));
+        assert_list_eq!(list, [9, 7, 7, 7]);
+        assert!(!list.replace_subsequence(&[8], [0]));
+        assert_list_eq!(list, [9, 7, 7, 7]);
+        assert!(list.replace_subsequence(&[], [0]));
+        assert_list_eq!(list, [0, 9, 7, 7, 7]);
+        assert!(list.replace_subsequence(&[0, 9, 7, 7, 7], []));
+        assert_list_eq!(list, []);
+        assert_consistent(&list);
+
+        let mut list: LinkedList<char> = "aaab".chars().collect();
+        assert!(list.replace_subsequence(&['a', 'a', 'b'], "xyzw".chars()));
+        assert_eq!(String::from(list), "axyzw");
+    }
+
+    #[test]
+    fn test_replace_all_subsequences() {
+        let mut list: LinkedList<char> = "abcabcab".chars().collect();
+        assert_eq!(list.replace_all_subsequences(&['a', 'b'], ['X']), 3);
+        assert_eq!(String::from(list.clone()), "XcXcX");
+        assert_consistent(&list);
+        assert_eq!(list.replace_all_subsequences(&['X'], []), 3);
+        assert_eq!(String::from(list.clone()), "cc");
+        assert_eq!(list.replace_all_subsequences(&['c'], "--".chars()), 2);
+        assert_eq!(String::from(list.clone()), "----");
+        assert_eq!(list.replace_all_subsequences(&['-', '-', '-'], ['+']), 1);
+        assert_eq!(String::from(list.clone()), "+-");
+        assert_eq!(list.replace_all_subsequences(&['z'], ['+']), 0);
+
+        for text in ["", "ab", "aab"] {
+            let mut list: LinkedList<char> = text.chars().collect();
+            let count = list.replace_all_subsequences(&[], ['.']);
+            assert_eq!(count, text.chars().count() + 1);
+            assert_eq!(String::from(list.clone()), text.replace("", "."));
+            assert_consistent(&list);
+        }
+    }
+
+    #[test]
+    fn test_bitor() {
+        assert_list_eq!(
+            linked_list![5, 1, 3] | linked_list![2, 6, 4],
+            [1, 2, 3, 4, 5, 6]
+        );
+        assert_list_eq!(linked_list![3, 1, 2] | linked_list![3, 1, 2], [1, 2, 3]);
+        let either = linked_list![5, 1, 3, 1, 1, 4] | linked_list![1, 4, 4, 1, 2];
+        assert_list_eq!(either, [1, 1, 1, 2, 3, 4, 4, 5]);
+        assert_consistent(&either);
+        assert_list_eq!(LinkedList::new() | linked_list![2, 1], [1, 2]);
+        assert_list_eq!(LinkedList::<i32>::new() | LinkedList::new(), []);
     }
 }
@@ -0,0 +1,78 @@
+//! `ufmt` formatting behind the `ufmt` feature, for targets where `core::fmt` is too heavy.
+
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::LinkedList;
+
+/// Writes the same `1 -> 2 -> 3 ` form as `Display`, one element at a time.
+impl<T: uDisplay> uDisplay for LinkedList<T> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        if self.head.is_none() {
+            return f.write_str("None");
+        }
+        let mut ptr = &self.head;
+        while let Some(node) = ptr {
+            node.data.fmt(f)?;
+            f.write_str(match node.next.is_some() {
+                true => " -> ",
+                false => " ",
+            })?;
+            ptr = &node.next;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the elements as a list, `[1, 2, 3]`.
+impl<T: uDebug> uDebug for LinkedList<T> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_list()?.entries(self.iter())?.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LinkedList;
+    use alloc::format;
+    use heapless::String;
+    use ufmt::uwrite;
+
+    fn display<T: ufmt::uDisplay>(list: &LinkedList<T>) -> String<64> {
+        let mut out = String::new();
+        uwrite!(out, "{}", list).unwrap();
+        out
+    }
+
+    fn debug<T: ufmt::uDebug>(list: &LinkedList<T>) -> String<64> {
+        let mut out = String::new();
+        uwrite!(out, "{:?}", list).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_udisplay() {
+        let list = linked_list![1u32, 20, 300];
+        assert_eq!(display(&list), "1 -> 20 -> 300 ");
+        assert_eq!(display(&list), format!("{}", list).as_str());
+        assert_eq!(display(&linked_list![-7i8]), "-7 ");
+        assert_eq!(display(&LinkedList::<u8>::new()), "None");
+        assert_eq!(
+            display(&LinkedList::<u8>::new()),
+            format!("{}", LinkedList::<u8>::new()).as_str()
+        );
+
+        let long = LinkedList::from([300u32; 20]);
+        let mut out = String::<16>::new();
+        assert!(uwrite!(out, "{}", long).is_err());
+    }
+
+    #[test]
+    fn test_udebug() {
+        assert_eq!(debug(&linked_list![1u32, 20, 300]), "[1, 20, 300]");
+        assert_eq!(
+            debug(&linked_list![linked_list![1u8, 2], LinkedList::new()]),
+            "[[1, 2], []]"
+        );
+        assert_eq!(debug(&LinkedList::<u8>::new()), "[]");
+    }
+}
@@ -0,0 +1,108 @@
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::{LinkedList, Node};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseListError<E> {
+    index: usize,
+    error: Option<E>,
+}
+
+impl<E> ParseListError<E> {
+    /// The position of the element that failed to parse.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The error returned by the element parser, or `None` if the element was empty.
+    pub fn element_error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+}
+
+impl<E: Display> Display for ParseListError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "invalid element at index {}: {}", self.index, error),
+            None => write!(f, "empty element at index {}", self.index),
+        }
+    }
+}
+
+/// Parses the format written by `Display`: elements separated by `->`, with whitespace around
+/// each element ignored. Both the empty string and `None` parse as the empty list.
+impl<T: FromStr> FromStr for LinkedList<T> {
+    type Err = ParseListError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut list = LinkedList::new();
+        if s.trim().is_empty() || s == "None" {
+            return Ok(list);
+        }
+        let mut tail = &mut list.head;
+        for (index, token) in s.split("->").enumerate() {
+            let token = token.trim();
+            if token.is_empty() {
+                return Err(ParseListError { index, error: None });
+            }
+            let data = token.parse().map_err(|error| ParseListError {
+                index,
+                error: Some(error),
+            })?;
+            tail = &mut tail.insert(Node::new(data)).next;
+            list.len += 1;
+        }
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseListError;
+    use crate::LinkedList;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use core::num::ParseIntError;
+
+    #[test]
+    fn test_round_trip() {
+        for list in [linked_list![1, 2, 3], linked_list![-7], LinkedList::new()] {
+            let parsed: LinkedList<i32> = format!("{}", list).parse().unwrap();
+            assert_list_eq!(parsed, list);
+        }
+
+        let list: LinkedList<String> = ["a", "bc", "None"].map(ToString::to_string).into();
+        let parsed: LinkedList<String> = format!("{}", list).parse().unwrap();
+        assert_list_eq!(parsed, list);
+        let list: LinkedList<String> = linked_list!["None".to_string()];
+        let parsed: LinkedList<String> = format!("{}", list).parse().unwrap();
+        assert_list_eq!(parsed, list);
+
+        let parsed: LinkedList<i32> = "".parse().unwrap();
+        assert!(parsed.is_empty());
+        let parsed: LinkedList<i32> = "1->2  ->   3".parse().unwrap();
+        assert_list_eq!(parsed, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = "1 -> x -> 3".parse::<LinkedList<i32>>().unwrap_err();
+        assert_eq!(error.index(), 1);
+        assert!(error.element_error().is_some());
+        assert!(format!("{}", error).starts_with("invalid element at index 1: "));
+
+        let error: ParseListError<ParseIntError> =
+            "1 -> 2 ->".parse::<LinkedList<i32>>().unwrap_err();
+        assert_eq!(error.index(), 2);
+        assert!(error.element_error().is_none());
+        assert_eq!(format!("{}", error), "empty element at index 2");
+
+        let error = "-> a".parse::<LinkedList<String>>().unwrap_err();
+        assert_eq!(error.index(), 0);
+        assert!(error.element_error().is_none());
+
+        let error = "a -> -> b".parse::<LinkedList<String>>().unwrap_err();
+        assert_eq!(error.index(), 1);
+    }
+}
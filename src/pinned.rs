@@ -0,0 +1,144 @@
+use core::pin::Pin;
+
+use crate::{Iter, LinkedList};
+
+/// A [`LinkedList`] whose elements are pinned: an element keeps its address from the moment
+/// it is appended until it is dropped, so it may hand out pointers to itself.
+///
+/// Every element lives in its own node and the list only ever relinks nodes, so inserting or
+/// removing other elements never moves it. Elements are dropped in place and never handed
+/// back by value.
+#[derive(Debug)]
+pub struct PinnedList<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Default for PinnedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PinnedList<T> {
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn append_pin(&mut self, data: T) -> Pin<&mut T> {
+        self.list.append(data);
+        self.back_pin_mut().unwrap()
+    }
+
+    pub fn front_pin_mut(&mut self) -> Option<Pin<&mut T>> {
+        self.list.first_mut().map(pin)
+    }
+
+    pub fn back_pin_mut(&mut self) -> Option<Pin<&mut T>> {
+        self.list.last_mut().map(pin)
+    }
+
+    pub fn iter_pin_mut(&mut self) -> impl Iterator<Item = Pin<&mut T>> + '_ {
+        self.list.iter_mut().map(pin)
+    }
+
+    /// Drops the element at `index` in place, returning `false` if it is out of range.
+    pub fn remove(&mut self, index: usize) -> bool {
+        self.list.unlink_at(index).is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+}
+
+fn pin<T>(data: &mut T) -> Pin<&mut T> {
+    // SAFETY: `data` lives inside a node owned by a `PinnedList`, which never moves elements
+    // out of their nodes, never swaps data between nodes and drops elements in place.
+    unsafe { Pin::new_unchecked(data) }
+}
+
+/// The elements are not pinned yet while they are in a plain list, so this is always safe.
+impl<T> From<LinkedList<T>> for PinnedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        Self { list }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PinnedList;
+    use alloc::vec::Vec;
+    use core::marker::PhantomPinned;
+    use core::pin::Pin;
+
+    struct Intrusive {
+        value: i32,
+        addr: usize,
+        _pin: PhantomPinned,
+    }
+
+    impl Intrusive {
+        fn new(value: i32) -> Self {
+            Self {
+                value,
+                addr: 0,
+                _pin: PhantomPinned,
+            }
+        }
+
+        fn register(self: Pin<&mut Self>) {
+            // SAFETY: only plain fields are written, nothing is moved.
+            let this = unsafe { self.get_unchecked_mut() };
+            this.addr = this as *const Self as usize;
+        }
+
+        fn registered(&self) -> bool {
+            self.addr == self as *const Self as usize
+        }
+    }
+
+    #[test]
+    fn test_addresses_are_stable() {
+        let mut list = PinnedList::new();
+        for value in 0..6 {
+            list.append_pin(Intrusive::new(value)).register();
+        }
+        let before: Vec<usize> = list.iter().map(|x| x.addr).collect();
+
+        assert!(list.remove(0));
+        assert!(list.remove(2));
+        assert!(!list.remove(10));
+        for value in 6..20 {
+            list.append_pin(Intrusive::new(value)).register();
+        }
+        assert!(list.remove(5));
+        assert_eq!(list.len(), 17);
+
+        assert!(list.iter().all(Intrusive::registered));
+        let kept: Vec<(i32, usize)> = list.iter().take(3).map(|x| (x.value, x.addr)).collect();
+        assert_eq!(kept, [(1, before[1]), (2, before[2]), (4, before[4])]);
+
+        for item in list.iter_pin_mut() {
+            item.register();
+        }
+        assert!(list.front_pin_mut().is_some_and(|x| x.registered()));
+        assert!(list.back_pin_mut().is_some_and(|x| x.value == 19));
+        list.clear();
+        assert!(list.is_empty());
+        assert!(list.front_pin_mut().is_none());
+    }
+}
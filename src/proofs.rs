@@ -0,0 +1,173 @@
+//! Bounded proofs for the relinking operations, checked with `cargo kani`.
+//!
+//! Lists hold up to `MAX_LEN` nondeterministic bytes and grow by at most `OPS`. The longest
+//! loop is the final comparison in `assert_matches`, which takes `MAX_LEN + OPS + 1 = 8`
+//! iterations, so an unwind bound of 9 covers every loop in the harnesses and the list walks.
+//! None of the harnesses expects a panic: `insert` and `remove` clamp out-of-range indices and
+//! the `pop`s return `None` on an empty list.
+//!
+//! Under `cargo test`, the same operations run exhaustively over every list of up to
+//! `MAX_LEN` distinct elements and every sequence of `OPS` operations. That covers the
+//! relinking without Kani, though not arbitrary element values.
+
+use alloc::vec::Vec;
+
+use crate::LinkedList;
+
+const MAX_LEN: usize = 4;
+const OPS: usize = 3;
+const OP_KINDS: u8 = 7;
+
+/// Returns a list of nondeterministic length and contents together with the same elements in
+/// a `Vec`.
+#[cfg(kani)]
+fn any_list() -> (LinkedList<u8>, Vec<u8>) {
+    let len: usize = kani::any();
+    kani::assume(len <= MAX_LEN);
+    let mut values = Vec::with_capacity(len);
+    for _ in 0..len {
+        values.push(kani::any());
+    }
+    (values.iter().copied().collect(), values)
+}
+
+/// Counts the nodes reachable from `head`, ignoring the stored `len`.
+fn reachable(list: &LinkedList<u8>) -> usize {
+    let mut count = 0;
+    let mut ptr = &list.head;
+    while let Some(node) = ptr {
+        count += 1;
+        ptr = &node.next;
+    }
+    count
+}
+
+fn assert_matches(list: &LinkedList<u8>, model: &[u8]) {
+    assert!(list.len() == reachable(list));
+    assert!(list.len() == model.len());
+    assert!(list.iter().eq(model));
+}
+
+/// Applies operation `op % OP_KINDS` to both the list and its `Vec` model.
+fn apply(list: &mut LinkedList<u8>, model: &mut Vec<u8>, op: u8, index: usize, data: u8) {
+    match op % OP_KINDS {
+        0 => {
+            list.append(data);
+            model.push(data);
+        }
+        1 => {
+            list.prepend(data);
+            model.insert(0, data);
+        }
+        2 => {
+            list.insert(data, index);
+            model.insert(index.min(model.len()), data);
+        }
+        3 => {
+            list.remove(index);
+            if !model.is_empty() {
+                model.remove(index.min(model.len() - 1));
+            }
+        }
+        4 => assert!(list.pop_front() == (!model.is_empty()).then(|| model.remove(0))),
+        5 => assert!(list.pop_last() == model.pop()),
+        _ => {
+            list.reverse();
+            model.reverse();
+        }
+    }
+}
+
+#[cfg(kani)]
+#[kani::proof]
+#[kani::unwind(9)]
+fn insert_then_remove_restores() {
+    let (mut list, values) = any_list();
+    let index: usize = kani::any();
+    kani::assume(index <= MAX_LEN + 1);
+    list.insert(kani::any(), index);
+    assert!(list.len() == values.len() + 1);
+    list.remove(index);
+    assert_matches(&list, &values);
+}
+
+#[cfg(kani)]
+#[kani::proof]
+#[kani::unwind(9)]
+fn len_tracks_reachable_nodes() {
+    let (mut list, mut model) = any_list();
+    for _ in 0..OPS {
+        let index: usize = kani::any();
+        kani::assume(index <= MAX_LEN + OPS);
+        apply(&mut list, &mut model, kani::any(), index, kani::any());
+        assert_matches(&list, &model);
+    }
+}
+
+#[cfg(kani)]
+#[kani::proof]
+#[kani::unwind(9)]
+fn reverse_twice_is_identity() {
+    let (mut list, values) = any_list();
+    list.reverse();
+    assert!(list.iter().eq(values.iter().rev()));
+    list.reverse();
+    assert_matches(&list, &values);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply, assert_matches, MAX_LEN, OPS, OP_KINDS};
+    use crate::LinkedList;
+    use alloc::vec::Vec;
+
+    /// Runs every sequence of `depth` operations from `list`, with fresh values for inserted
+    /// elements so that a misplaced node shows up in the comparison.
+    fn explore(list: &LinkedList<u8>, model: &[u8], depth: usize) -> usize {
+        if depth == 0 {
+            return 1;
+        }
+        let data = 100 + depth as u8;
+        let mut runs = 0;
+        for op in 0..OP_KINDS {
+            let indices = match op {
+                2 | 3 => 0..=MAX_LEN + OPS,
+                _ => 0..=0,
+            };
+            for index in indices {
+                let (mut list, mut model) = (list.clone(), model.to_vec());
+                apply(&mut list, &mut model, op, index, data);
+                assert_matches(&list, &model);
+                runs += explore(&list, &model, depth - 1);
+            }
+        }
+        runs
+    }
+
+    #[test]
+    fn test_bounded_exhaustive() {
+        let mut runs = 0;
+        for len in 0..=MAX_LEN as u8 {
+            let values: Vec<u8> = (0..len).collect();
+            let list: LinkedList<u8> = values.iter().copied().collect();
+
+            for index in 0..=MAX_LEN + 1 {
+                let mut list = list.clone();
+                list.insert(99, index);
+                assert_eq!(list.len(), values.len() + 1);
+                list.remove(index);
+                assert_matches(&list, &values);
+            }
+
+            let mut reversed = list.clone();
+            reversed.reverse();
+            assert!(reversed.iter().eq(values.iter().rev()));
+            reversed.reverse();
+            assert_matches(&reversed, &values);
+
+            runs += explore(&list, &values, OPS);
+        }
+        let choices = OP_KINDS as usize - 2 + 2 * (MAX_LEN + OPS + 1);
+        assert_eq!(runs, (MAX_LEN + 1) * choices.pow(OPS as u32));
+    }
+}
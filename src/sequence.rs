@@ -0,0 +1,393 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use crate::LinkedList;
+
+/// Operations shared by [`LinkedList`], [`Vec`] and [`VecDeque`], so that algorithms can be
+/// written once and run against any of them.
+pub trait Sequence<T> {
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    type IterMut<'a>: Iterator<Item = &'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push_back(&mut self, data: T);
+
+    fn push_front(&mut self, data: T);
+
+    fn pop_front(&mut self) -> Option<T>;
+
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Inserts `data` so that it ends up at `index`. Panics if `index > len`.
+    fn insert(&mut self, index: usize, data: T);
+
+    /// Removes and returns the element at `index`, or returns `None` if it is out of range.
+    fn remove(&mut self, index: usize) -> Option<T>;
+
+    fn first(&self) -> Option<&T>;
+
+    fn first_mut(&mut self) -> Option<&mut T>;
+
+    fn last(&self) -> Option<&T>;
+
+    fn last_mut(&mut self) -> Option<&mut T>;
+
+    fn get(&self, index: usize) -> Option<&T>;
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+
+    fn clear(&mut self);
+
+    fn reverse(&mut self);
+
+    fn iter(&self) -> Self::Iter<'_>;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+}
+
+impl<T> Sequence<T> for LinkedList<T> {
+    type Iter<'a>
+        = crate::Iter<'a, T>
+    where
+        T: 'a;
+
+    type IterMut<'a>
+        = crate::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn push_back(&mut self, data: T) {
+        self.append(data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        self.prepend(data);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        LinkedList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_last()
+    }
+
+    fn insert(&mut self, index: usize, data: T) {
+        assert!(
+            index <= self.len,
+            "insertion index {index} out of range for length {}",
+            self.len
+        );
+        self.insert_mut(data, index);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        self.pop_at(index)
+    }
+
+    fn first(&self) -> Option<&T> {
+        LinkedList::first(self)
+    }
+
+    fn first_mut(&mut self) -> Option<&mut T> {
+        LinkedList::first_mut(self)
+    }
+
+    fn last(&self) -> Option<&T> {
+        LinkedList::last(self)
+    }
+
+    fn last_mut(&mut self) -> Option<&mut T> {
+        LinkedList::last_mut(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        LinkedList::iter(self).nth(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        LinkedList::iter_mut(self).nth(index)
+    }
+
+    fn clear(&mut self) {
+        LinkedList::clear(self);
+    }
+
+    fn reverse(&mut self) {
+        LinkedList::reverse(self);
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        LinkedList::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        LinkedList::iter_mut(self)
+    }
+}
+
+impl<T> Sequence<T> for Vec<T> {
+    type Iter<'a>
+        = core::slice::Iter<'a, T>
+    where
+        T: 'a;
+
+    type IterMut<'a>
+        = core::slice::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn push_back(&mut self, data: T) {
+        self.push(data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        Vec::insert(self, 0, data);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        Sequence::remove(self, 0)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    fn insert(&mut self, index: usize, data: T) {
+        Vec::insert(self, index, data);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index < Vec::len(self) {
+            Some(Vec::remove(self, index))
+        } else {
+            None
+        }
+    }
+
+    fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    fn first_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().first_mut()
+    }
+
+    fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    fn last_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    fn clear(&mut self) {
+        Vec::clear(self);
+    }
+
+    fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T> Sequence<T> for VecDeque<T> {
+    type Iter<'a>
+        = alloc::collections::vec_deque::Iter<'a, T>
+    where
+        T: 'a;
+
+    type IterMut<'a>
+        = alloc::collections::vec_deque::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn push_back(&mut self, data: T) {
+        VecDeque::push_back(self, data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        VecDeque::push_front(self, data);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        VecDeque::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        VecDeque::pop_back(self)
+    }
+
+    fn insert(&mut self, index: usize, data: T) {
+        VecDeque::insert(self, index, data);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        VecDeque::remove(self, index)
+    }
+
+    fn first(&self) -> Option<&T> {
+        self.front()
+    }
+
+    fn first_mut(&mut self) -> Option<&mut T> {
+        self.front_mut()
+    }
+
+    fn last(&self) -> Option<&T> {
+        self.back()
+    }
+
+    fn last_mut(&mut self) -> Option<&mut T> {
+        self.back_mut()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        VecDeque::get(self, index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        VecDeque::get_mut(self, index)
+    }
+
+    fn clear(&mut self) {
+        VecDeque::clear(self);
+    }
+
+    fn reverse(&mut self) {
+        self.make_contiguous().reverse();
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        VecDeque::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        VecDeque::iter_mut(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequence;
+    use crate::LinkedList;
+    use alloc::collections::VecDeque;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn dedup<S: Sequence<i32>>(seq: &mut S) {
+        let mut len = seq.len();
+        let mut prev = None;
+        while len > 0 {
+            let data = seq.pop_front().unwrap();
+            if prev != Some(data) {
+                seq.push_back(data);
+            }
+            prev = Some(data);
+            len -= 1;
+        }
+    }
+
+    fn check<S: Sequence<i32> + From<Vec<i32>>>(data: &[i32]) -> Vec<i32> {
+        let mut seq = S::from(data.to_vec());
+        assert_eq!(seq.len(), data.len());
+        assert_eq!(seq.first(), data.first());
+        assert_eq!(seq.last(), data.last());
+        assert_eq!(seq.get(1), data.get(1));
+        dedup(&mut seq);
+        let result: Vec<i32> = seq.iter().copied().collect();
+
+        let mut model: Vec<i32> = result.iter().map(|data| data * 10).collect();
+        for data in seq.iter_mut() {
+            *data *= 10;
+        }
+        if let Some(first) = seq.first_mut() {
+            *first += 1;
+            model[0] += 1;
+        }
+        if let Some(second) = seq.get_mut(1) {
+            *second += 2;
+            model[1] += 2;
+        }
+        if let Some(last) = seq.last_mut() {
+            *last += 4;
+            *model.last_mut().unwrap() += 4;
+        }
+        assert_eq!(seq.get_mut(seq.len()), None);
+        seq.reverse();
+        model.reverse();
+        let len = seq.len();
+        seq.insert(len / 2, -1);
+        model.insert(len / 2, -1);
+        seq.insert(len + 1, -2);
+        model.insert(len + 1, -2);
+        assert!(seq.iter().eq(&model));
+        assert_eq!(seq.remove(len + 2), None);
+        assert_eq!(seq.remove(len / 2), Some(-1));
+        assert_eq!(seq.remove(len), Some(-2));
+        seq.reverse();
+        model.retain(|&data| data >= 0);
+        model.reverse();
+        assert!(seq.iter().eq(&model));
+
+        seq.push_front(0);
+        assert_eq!(seq.pop_front(), Some(0));
+        assert_eq!(seq.pop_back(), model.last().copied());
+        seq.clear();
+        assert!(seq.is_empty());
+        result
+    }
+
+    #[test]
+    fn test_sequence() {
+        for data in [vec![], vec![1], vec![1, 1, 2, 3, 3, 3, 1, 4, 4]] {
+            let expected = check::<Vec<i32>>(&data);
+            assert_eq!(check::<VecDeque<i32>>(&data), expected);
+            assert_eq!(check::<LinkedList<i32>>(&data), expected);
+        }
+    }
+    #[test]
+    #[should_panic(expected = "insertion index 3 out of range for length 2")]
+    fn test_insert_out_of_range() {
+        Sequence::insert(&mut linked_list![1, 2], 3, 0);
+    }
+}
@@ -0,0 +1,19 @@
+//! Fixtures shared by the test modules.
+
+use alloc::vec::Vec;
+
+/// A xorshift generator, so tests get reproducible pseudo-random input without a dependency.
+pub(crate) struct Rng(pub(crate) u64);
+
+impl Rng {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub(crate) fn vec(&mut self, len: usize, max: u64) -> Vec<u64> {
+        (0..len).map(|_| self.next() % max).collect()
+    }
+}
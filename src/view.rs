@@ -0,0 +1,210 @@
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::RangeBounds;
+
+use crate::{resolve_range, Iter, LinkedList, Node};
+
+/// A borrowed, contiguous part of a [`LinkedList`].
+pub struct ListView<'a, T> {
+    head: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<T> Clone for ListView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListView<'_, T> {}
+
+impl<T> LinkedList<T> {
+    pub fn as_view(&self) -> ListView<'_, T> {
+        ListView {
+            head: self.head.as_deref(),
+            len: self.len,
+        }
+    }
+
+    /// Borrows the elements in `range`, walking only up to the start of the range.
+    pub fn view<R: RangeBounds<usize>>(&self, range: R) -> ListView<'_, T> {
+        self.as_view().view(range)
+    }
+
+    pub fn split_first(&self) -> Option<(&T, ListView<'_, T>)> {
+        self.as_view().split_first()
+    }
+}
+
+impl<'a, T> ListView<'a, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn first(&self) -> Option<&'a T> {
+        self.iter().next()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        self.iter().nth(index)
+    }
+
+    pub fn split_first(&self) -> Option<(&'a T, ListView<'a, T>)> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.head?;
+        let rest = ListView {
+            head: node.next.as_deref(),
+            len: self.len - 1,
+        };
+        Some((&node.data, rest))
+    }
+
+    pub fn view<R: RangeBounds<usize>>(&self, range: R) -> ListView<'a, T> {
+        let range = resolve_range(range, self.len);
+        let mut head = self.head;
+        for _ in 0..range.start {
+            head = head.and_then(|node| node.next.as_deref());
+        }
+        ListView {
+            head,
+            len: range.len(),
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            ptr: self.head,
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for ListView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &ListView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Display> Display for ListView<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "None");
+        }
+        for (i, data) in self.iter().enumerate() {
+            write!(
+                f,
+                "{} {}",
+                data,
+                match i + 1 < self.len {
+                    true => "-> ",
+                    false => "",
+                }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug> Debug for ListView<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ListView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<LinkedList<T>> for ListView<'_, T> {
+    fn eq(&self, other: &LinkedList<T>) -> bool {
+        *self == other.as_view()
+    }
+}
+
+impl<T: PartialEq> PartialEq<ListView<'_, T>> for LinkedList<T> {
+    fn eq(&self, other: &ListView<'_, T>) -> bool {
+        self.as_view() == *other
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for ListView<'_, T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ListView;
+    use crate::LinkedList;
+    use alloc::format;
+
+    fn sum(view: ListView<'_, i32>) -> i32 {
+        match view.split_first() {
+            Some((first, rest)) => first + sum(rest),
+            None => 0,
+        }
+    }
+
+    #[test]
+    fn test_split_first() {
+        let list = linked_list![1, 2, 3, 4];
+        assert_eq!(sum(list.as_view()), 10);
+
+        let (first, rest) = list.split_first().unwrap();
+        assert_eq!(*first, 1);
+        assert_eq!(rest.len(), 3);
+        assert!(rest == linked_list![2, 3, 4]);
+        let (second, rest) = rest.split_first().unwrap();
+        assert_eq!(*second, 2);
+        assert_eq!(rest.first(), Some(&3));
+        assert!(LinkedList::<i32>::new().split_first().is_none());
+    }
+
+    #[test]
+    fn test_view() {
+        let list = linked_list![1, 2, 3, 4, 5];
+        let view = list.view(1..4);
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.get(0), Some(&2));
+        assert_eq!(view.get(2), Some(&4));
+        assert_eq!(view.get(3), None);
+        assert!(view == &[2, 3, 4][..]);
+        assert!(view == linked_list![2, 3, 4]);
+        assert!(linked_list![2, 3, 4] == view);
+        assert!(view != linked_list![2, 3]);
+        assert_eq!(format!("{}", view), "2 -> 3 -> 4 ");
+        assert_eq!(format!("{:?}", view), "[2, 3, 4]");
+
+        let copy = view;
+        assert_eq!(copy.view(1..), view.view(1..=2));
+        assert_eq!(format!("{}", list.view(5..)), "None");
+        assert!(list.view(..) == list);
+        assert_eq!(list.view(..2).iter().copied().sum::<i32>(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_view_out_of_range() {
+        linked_list![1, 2, 3].view(1..5);
+    }
+}
@@ -0,0 +1,194 @@
+//! Best-effort scrubbing of list elements behind the `zeroize` feature.
+//!
+//! A plain [`LinkedList`] drops each element inside its node and frees the node right after,
+//! and `pop_front` moves the element out of its node before freeing it, so neither path can
+//! zeroize an arbitrary `T`. [`ZeroizingList`] requires `T: Zeroize` and zeroizes every
+//! element that leaves the list before its node is freed.
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Iter, IterMut, LinkedList};
+
+/// Zeroizes every element in place. The nodes and the length are left as they are.
+impl<T: Zeroize> Zeroize for LinkedList<T> {
+    fn zeroize(&mut self) {
+        for data in self.iter_mut() {
+            data.zeroize();
+        }
+    }
+}
+
+/// A [`LinkedList`] that zeroizes its elements before their nodes are freed.
+///
+/// [`remove`](Self::remove), [`clear`](Self::clear) and dropping the list zeroize each element
+/// in place and only then drop it and free its node. [`pop_front`](Self::pop_front) moves the
+/// element out and leaves a zeroized `T::default()` behind in the node, so the node memory
+/// no longer holds the value when it is freed; padding bytes are not covered.
+#[derive(Debug)]
+pub struct ZeroizingList<T: Zeroize> {
+    list: LinkedList<T>,
+}
+
+impl<T: Zeroize> Default for ZeroizingList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Zeroize> ZeroizingList<T> {
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+
+    pub fn append(&mut self, data: T) {
+        self.list.append(data);
+    }
+
+    pub fn prepend(&mut self, data: T) {
+        self.list.prepend(data);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T>
+    where
+        T: Default,
+    {
+        let mut node = self.list.pop_front_node()?;
+        let data = core::mem::take(&mut node.data);
+        node.data.zeroize();
+        Some(data)
+    }
+
+    /// Zeroizes and drops the element at `index`, returning `false` if it is out of range.
+    pub fn remove(&mut self, index: usize) -> bool {
+        match self.list.unlink_at(index) {
+            Some(mut node) => {
+                node.data.zeroize();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.list.zeroize();
+        self.list.clear();
+    }
+}
+
+impl<T: Zeroize> Zeroize for ZeroizingList<T> {
+    fn zeroize(&mut self) {
+        self.list.zeroize();
+    }
+}
+
+/// The elements are zeroized first; the inner list then drops them and frees the nodes.
+impl<T: Zeroize> Drop for ZeroizingList<T> {
+    fn drop(&mut self) {
+        self.list.zeroize();
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for ZeroizingList<T> {}
+
+impl<T: Zeroize> From<LinkedList<T>> for ZeroizingList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        Self { list }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ZeroizingList;
+    use crate::LinkedList;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+    use zeroize::Zeroize;
+
+    /// Records, when dropped, its id and whether it had been zeroized.
+    #[derive(Default)]
+    struct Canary<'a> {
+        id: u32,
+        secret: u64,
+        drops: Option<&'a RefCell<Vec<(u32, bool)>>>,
+    }
+
+    impl Zeroize for Canary<'_> {
+        fn zeroize(&mut self) {
+            self.secret.zeroize();
+        }
+    }
+
+    impl Drop for Canary<'_> {
+        fn drop(&mut self) {
+            if let Some(drops) = self.drops {
+                drops.borrow_mut().push((self.id, self.secret == 0));
+            }
+        }
+    }
+
+    fn canary(id: u32, drops: &RefCell<Vec<(u32, bool)>>) -> Canary<'_> {
+        Canary {
+            id,
+            secret: 0xdead_beef,
+            drops: Some(drops),
+        }
+    }
+
+    #[test]
+    fn test_zeroize_list() {
+        let mut list = linked_list![1u8, 2, 3];
+        list.zeroize();
+        assert_list_eq!(list, [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_zeroize_before_drop() {
+        let drops = RefCell::new(Vec::new());
+        let mut list = ZeroizingList::new();
+        for id in 0..6 {
+            list.append(canary(id, &drops));
+        }
+
+        assert!(list.remove(1));
+        assert!(!list.remove(10));
+        assert_eq!(*drops.borrow(), [(1, true)]);
+
+        let popped = list.pop_front().unwrap();
+        assert_eq!((popped.id, popped.secret), (0, 0xdead_beef));
+        assert_eq!(*drops.borrow(), [(1, true)]);
+        drop(popped);
+        drops.borrow_mut().clear();
+
+        list.prepend(canary(6, &drops));
+        assert_eq!(list.len(), 5);
+        drop(list);
+        assert_eq!(
+            *drops.borrow(),
+            [(6, true), (2, true), (3, true), (4, true), (5, true)]
+        );
+
+        drops.borrow_mut().clear();
+        let mut list = ZeroizingList::from(LinkedList::from([canary(7, &drops)]));
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(*drops.borrow(), [(7, true)]);
+    }
+}